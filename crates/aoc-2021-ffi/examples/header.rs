@@ -0,0 +1,5 @@
+//! Prints the C header for `libaoc2021`.
+
+fn main() {
+    print!("{}", aoc2021::header());
+}
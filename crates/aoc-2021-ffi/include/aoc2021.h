@@ -0,0 +1,50 @@
+/* Generated by `cargo run -p aoc-2021-ffi --example header`; do not edit. */
+
+#ifndef AOC2021_H
+#define AOC2021_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Status codes returned by aoc2021_solve. */
+#define AOC2021_OK               0 /* the answer was written to the output buffer */
+#define AOC2021_NULL_POINTER     1 /* a required pointer was null */
+#define AOC2021_INVALID_UTF8     2 /* the input is not valid UTF-8 */
+#define AOC2021_UNKNOWN_DAY      3 /* the day has not been solved */
+#define AOC2021_UNKNOWN_PART     4 /* the day has no such part */
+#define AOC2021_INVALID_INPUT    5 /* the solver rejected the input */
+#define AOC2021_PANICKED         6 /* the solver panicked */
+#define AOC2021_BUFFER_TOO_SMALL 7 /* the output buffer is too small */
+
+/*
+ * Solves a part of a day against `input_len` bytes of UTF-8 input.
+ *
+ * On entry, `*out_len` is the capacity of `out_buf`. On success the answer
+ * is written there as a NUL-terminated string, either a decimal integer or
+ * text, and `*out_len` is set to its length. If the buffer is too small,
+ * `*out_len` is set to the length needed, excluding the terminator. When
+ * the solver rejects the input or panics, as much of the reason as fits is
+ * written to `out_buf` instead.
+ */
+int32_t aoc2021_solve(uint8_t day,
+                      uint8_t part,
+                      const uint8_t *input_ptr,
+                      size_t input_len,
+                      char *out_buf,
+                      size_t *out_len);
+
+/*
+ * Describes a status code as a static NUL-terminated string, or returns NULL
+ * for codes the library never produces.
+ */
+const char *aoc2021_status_message(int32_t status);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC2021_H */
@@ -0,0 +1,320 @@
+//! The 2021 solvers behind a C ABI, built as `libaoc2021`. The matching
+//! header is `include/aoc2021.h`, generated by [`header`]. With the `python`
+//! feature, the same library is also the `aoc2021` Python module.
+
+#[cfg(feature = "python")]
+mod python;
+
+use aoc_2021::solutions::find_day;
+use aoc_core::{c_abi::write_c_string, runner::panic_message};
+use std::{
+    ffi::{c_char, CStr},
+    fmt::Write,
+    panic::{self, AssertUnwindSafe},
+    ptr, slice, str,
+};
+
+/// What a call into the library can report, as returned to C.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(i32)]
+pub enum Status {
+    Ok = 0,
+    NullPointer = 1,
+    InvalidUtf8 = 2,
+    UnknownDay = 3,
+    UnknownPart = 4,
+    InvalidInput = 5,
+    Panicked = 6,
+    BufferTooSmall = 7,
+}
+
+impl Status {
+    pub const ALL: [Self; 8] = [
+        Self::Ok,
+        Self::NullPointer,
+        Self::InvalidUtf8,
+        Self::UnknownDay,
+        Self::UnknownPart,
+        Self::InvalidInput,
+        Self::Panicked,
+        Self::BufferTooSmall,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Ok => "AOC2021_OK",
+            Self::NullPointer => "AOC2021_NULL_POINTER",
+            Self::InvalidUtf8 => "AOC2021_INVALID_UTF8",
+            Self::UnknownDay => "AOC2021_UNKNOWN_DAY",
+            Self::UnknownPart => "AOC2021_UNKNOWN_PART",
+            Self::InvalidInput => "AOC2021_INVALID_INPUT",
+            Self::Panicked => "AOC2021_PANICKED",
+            Self::BufferTooSmall => "AOC2021_BUFFER_TOO_SMALL",
+        }
+    }
+
+    /// NUL-terminated, so that it can be handed straight to C.
+    fn message(self) -> &'static CStr {
+        match self {
+            Self::Ok => c"the answer was written to the output buffer",
+            Self::NullPointer => c"a required pointer was null",
+            Self::InvalidUtf8 => c"the input is not valid UTF-8",
+            Self::UnknownDay => c"the day has not been solved",
+            Self::UnknownPart => c"the day has no such part",
+            Self::InvalidInput => c"the solver rejected the input",
+            Self::Panicked => c"the solver panicked",
+            Self::BufferTooSmall => c"the output buffer is too small",
+        }
+    }
+}
+
+/// Solves a part of a day against `input_len` bytes of UTF-8 input.
+///
+/// On entry, `*out_len` is the capacity of `out_buf`. On success the answer
+/// is written there as a NUL-terminated string, either a decimal integer or
+/// text, and `*out_len` is set to its length. If the buffer is too small,
+/// `*out_len` is set to the length needed, excluding the terminator. When
+/// the solver rejects the input or panics, as much of the reason as fits is
+/// written to `out_buf` instead.
+///
+/// # Safety
+///
+/// `input_ptr` must be valid for reads of `input_len` bytes, `out_len` must
+/// be valid for reads and writes, and `out_buf` must be valid for writes of
+/// `*out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc2021_solve(
+    day: u8,
+    part: u8,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_len: *mut usize,
+) -> i32 {
+    solve(day, part, input_ptr, input_len, out_buf, out_len) as i32
+}
+
+unsafe fn solve(
+    day: u8,
+    part: u8,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_len: *mut usize,
+) -> Status {
+    if input_ptr.is_null() || out_buf.is_null() || out_len.is_null() {
+        return Status::NullPointer;
+    }
+
+    let Ok(input) = str::from_utf8(slice::from_raw_parts(input_ptr, input_len)) else {
+        return Status::InvalidUtf8;
+    };
+    let Ok(day) = find_day(day) else {
+        return Status::UnknownDay;
+    };
+    let Ok(part) = day.part(part) else {
+        return Status::UnknownPart;
+    };
+
+    let capacity = *out_len;
+    match panic::catch_unwind(AssertUnwindSafe(|| (part.solve)(input))) {
+        Ok(Ok(answer)) => {
+            let answer = answer.to_string();
+            *out_len = answer.len();
+            if write_c_string(&answer, out_buf, capacity) {
+                Status::Ok
+            } else {
+                Status::BufferTooSmall
+            }
+        }
+        Ok(Err(e)) => {
+            write_c_string(&format!("{:#}", e), out_buf, capacity);
+            Status::InvalidInput
+        }
+        Err(payload) => {
+            write_c_string(panic_message(payload.as_ref()), out_buf, capacity);
+            Status::Panicked
+        }
+    }
+}
+
+/// Describes a status code as a static NUL-terminated string, or returns null
+/// for codes the library never produces.
+#[no_mangle]
+pub extern "C" fn aoc2021_status_message(status: i32) -> *const c_char {
+    Status::ALL
+        .iter()
+        .find(|&&s| s as i32 == status)
+        .map_or(ptr::null(), |s| s.message().as_ptr())
+}
+
+/// The C header declaring everything the library exports.
+pub fn header() -> String {
+    let mut h = String::from(
+        "/* Generated by `cargo run -p aoc-2021-ffi --example header`; do not edit. */
+
+#ifndef AOC2021_H
+#define AOC2021_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern \"C\" {
+#endif
+
+/* Status codes returned by aoc2021_solve. */
+",
+    );
+
+    let width = Status::ALL
+        .iter()
+        .map(|s| s.name().len())
+        .max()
+        .unwrap_or(0);
+    for status in Status::ALL {
+        // Writing to a String can't fail.
+        let _ = writeln!(
+            h,
+            "#define {:<width$} {} /* {} */",
+            status.name(),
+            status as i32,
+            status.message().to_string_lossy(),
+            width = width
+        );
+    }
+
+    h.push_str(
+        "
+/*
+ * Solves a part of a day against `input_len` bytes of UTF-8 input.
+ *
+ * On entry, `*out_len` is the capacity of `out_buf`. On success the answer
+ * is written there as a NUL-terminated string, either a decimal integer or
+ * text, and `*out_len` is set to its length. If the buffer is too small,
+ * `*out_len` is set to the length needed, excluding the terminator. When
+ * the solver rejects the input or panics, as much of the reason as fits is
+ * written to `out_buf` instead.
+ */
+int32_t aoc2021_solve(uint8_t day,
+                      uint8_t part,
+                      const uint8_t *input_ptr,
+                      size_t input_len,
+                      char *out_buf,
+                      size_t *out_len);
+
+/*
+ * Describes a status code as a static NUL-terminated string, or returns NULL
+ * for codes the library never produces.
+ */
+const char *aoc2021_status_message(int32_t status);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC2021_H */
+",
+    );
+
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2021::day_04;
+
+    /// The signatures the header declares, so that a mismatch fails to build.
+    type Solve = unsafe extern "C" fn(u8, u8, *const u8, usize, *mut c_char, *mut usize) -> i32;
+    type StatusMessage = extern "C" fn(i32) -> *const c_char;
+
+    fn call(day: u8, part: u8, input: &[u8], capacity: usize) -> (i32, usize, String) {
+        let c_solve: Solve = aoc2021_solve;
+        let mut out = vec![0x7f as c_char; capacity.max(1)];
+        let mut len = capacity;
+
+        let status = unsafe {
+            c_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr(),
+                &mut len,
+            )
+        };
+        let text = if capacity == 0 {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(out.as_ptr()) }
+                .to_string_lossy()
+                .into_owned()
+        };
+
+        (status, len, text)
+    }
+
+    #[test]
+    fn solves_through_the_c_abi() {
+        let (status, len, answer) = call(4, 1, day_04::EXAMPLE_INPUT.as_bytes(), 32);
+
+        assert_eq!(status, Status::Ok as i32);
+        assert_eq!(answer, "4512");
+        assert_eq!(len, 4);
+    }
+
+    #[test]
+    fn reports_the_length_needed_when_the_buffer_is_too_small() {
+        let (status, len, answer) = call(4, 1, day_04::EXAMPLE_INPUT.as_bytes(), 4);
+
+        assert_eq!(status, Status::BufferTooSmall as i32);
+        assert_eq!(len, 4);
+        assert_eq!(answer, "451");
+
+        let (status, _, _) = call(4, 1, day_04::EXAMPLE_INPUT.as_bytes(), 0);
+        assert_eq!(status, Status::BufferTooSmall as i32);
+    }
+
+    #[test]
+    fn maps_errors_to_status_codes() {
+        assert_eq!(call(5, 1, b"", 32).0, Status::UnknownDay as i32);
+        assert_eq!(call(3, 2, b"", 32).0, Status::UnknownPart as i32);
+        assert_eq!(call(7, 1, b"1,\xff", 32).0, Status::InvalidUtf8 as i32);
+
+        let (status, _, reason) = call(7, 1, b"1,x", 64);
+        assert_eq!(status, Status::InvalidInput as i32);
+        assert!(!reason.is_empty());
+    }
+
+    #[test]
+    fn rejects_null_pointers() {
+        let c_solve: Solve = aoc2021_solve;
+        let mut len = 8;
+        let status = unsafe { c_solve(1, 1, ptr::null(), 0, ptr::null_mut(), &mut len) };
+
+        assert_eq!(status, Status::NullPointer as i32);
+    }
+
+    #[test]
+    fn describes_every_status() {
+        let message: StatusMessage = aoc2021_status_message;
+
+        for status in Status::ALL {
+            let text = unsafe { CStr::from_ptr(message(status as i32)) };
+            assert_eq!(text, status.message());
+        }
+        assert!(message(-1).is_null());
+    }
+
+    #[test]
+    fn header_is_up_to_date() {
+        let committed = include_str!("../include/aoc2021.h");
+
+        assert!(
+            committed == header(),
+            "include/aoc2021.h is stale; regenerate it with \
+             `cargo run -p aoc-2021-ffi --example header > crates/aoc-2021-ffi/include/aoc2021.h`"
+        );
+    }
+}
@@ -0,0 +1,148 @@
+//! The `aoc2021` Python extension module, for exploring puzzles from notebooks.
+
+use aoc_2021::{
+    day_06::part_02::{self as lantern_fish, School},
+    day_08::part_02::{self as seven_segment, Decoding},
+    solutions::{find_day, DAYS},
+};
+use aoc_core::{answer::Answer, runner};
+use pyo3::{
+    exceptions::{PyKeyError, PyValueError},
+    prelude::*,
+};
+use std::collections::HashMap;
+
+fn value_error(e: anyhow::Error) -> PyErr {
+    PyValueError::new_err(format!("{:#}", e))
+}
+
+/// Solves a part of a day against the given input, answering with an int,
+/// however large, or a str for text answers.
+#[pyfunction]
+fn solve(py: Python<'_>, day: u8, part: u8, input: &str) -> PyResult<PyObject> {
+    let part = find_day(day)
+        .and_then(|day| day.part(part))
+        .map_err(|e| PyKeyError::new_err(e.to_string()))?;
+
+    let answer = match runner::solve(part.solve, input).map_err(value_error)? {
+        Answer::Int(n) => n.into_pyobject(py)?.into_any(),
+        Answer::BigInt(n) => n.into_pyobject(py)?.into_any(),
+        Answer::Text(text) => text.into_pyobject(py)?.into_any(),
+    };
+
+    Ok(answer.unbind())
+}
+
+/// Every solved `(day, part)`, in order.
+#[pyfunction]
+fn list_solutions() -> Vec<(u8, u8)> {
+    DAYS.iter()
+        .flat_map(|day| day.parts.iter().map(move |part| (day.number, part.number)))
+        .collect()
+}
+
+/// Day 6's lantern fish, simulated a day at a time.
+#[pyclass(name = "School")]
+struct PySchool(School);
+
+#[pymethods]
+impl PySchool {
+    #[new]
+    fn new(input: &str) -> PyResult<Self> {
+        School::parse(input).map(Self).map_err(value_error)
+    }
+
+    #[getter]
+    fn days_elapsed(&self) -> usize {
+        self.0.days_elapsed()
+    }
+
+    #[getter]
+    fn population(&self) -> u64 {
+        self.0.total_fish()
+    }
+
+    /// How many fish have each timer value, indexed by timer.
+    #[getter]
+    fn timers(&self) -> Vec<u64> {
+        self.0.timers().to_vec()
+    }
+
+    /// Simulates `days` more days, returning the population after each.
+    #[pyo3(signature = (days = 1))]
+    fn step(&mut self, days: usize) -> Vec<u64> {
+        (0..days)
+            .map(|_| {
+                self.0.step();
+                self.0.total_fish()
+            })
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "School(days_elapsed={}, population={})",
+            self.0.days_elapsed(),
+            self.0.total_fish()
+        )
+    }
+}
+
+/// The population at the start, then after each of `days` days.
+#[pyfunction]
+fn populations(input: &str, days: usize) -> PyResult<Vec<u64>> {
+    lantern_fish::populations(input, days).map_err(value_error)
+}
+
+/// Day 8's wiring for one display, as deduced from its signal patterns.
+#[pyclass(name = "Decoding", frozen)]
+struct PyDecoding(Decoding);
+
+#[pymethods]
+impl PyDecoding {
+    /// Each digit's signal pattern, with its segments sorted, indexed by digit.
+    #[getter]
+    fn patterns(&self) -> Vec<String> {
+        self.0.patterns.to_vec()
+    }
+
+    #[getter]
+    fn output_value(&self) -> u64 {
+        self.0.output_value
+    }
+
+    /// Maps each signal pattern to the digit it shows.
+    fn mapping(&self) -> HashMap<String, u8> {
+        (0..)
+            .zip(&self.0.patterns)
+            .map(|(digit, pattern)| (pattern.clone(), digit))
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Decoding(patterns={:?}, output_value={})",
+            self.0.patterns, self.0.output_value
+        )
+    }
+}
+
+/// Decodes every display in a day 8 input.
+#[pyfunction]
+fn decode_displays(input: &str) -> PyResult<Vec<PyDecoding>> {
+    seven_segment::decodings(input)
+        .map(|decodings| decodings.into_iter().map(PyDecoding).collect())
+        .map_err(value_error)
+}
+
+#[pymodule]
+fn aoc2021(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(list_solutions, m)?)?;
+    m.add_function(wrap_pyfunction!(populations, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_displays, m)?)?;
+    m.add_class::<PySchool>()?;
+    m.add_class::<PyDecoding>()?;
+
+    Ok(())
+}
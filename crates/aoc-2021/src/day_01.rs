@@ -0,0 +1,52 @@
+pub mod part_01;
+pub mod part_02;
+
+use alloc::{format, vec};
+use anyhow::anyhow;
+use aoc_core::{
+    bytes,
+    inspect::{numbered_lines, Report},
+};
+use itertools::Itertools;
+#[cfg(feature = "std")]
+use {aoc_core::input::records, std::io::BufRead};
+
+pub const EXAMPLE_INPUT: &str = include_str!("../resources/day_01/example_input.txt");
+pub const PUZZLE_INPUT: &str = include_str!("../resources/day_01/puzzle_input.txt");
+pub const ANSWERS: &str = include_str!("../resources/day_01/answers.txt");
+
+fn parse_depth(depth: &[u8]) -> anyhow::Result<u64> {
+    bytes::parse_u64(depth).ok_or_else(|| anyhow!("depth is invalid: '{}'", bytes::show(depth)))
+}
+
+/// Parses the depths in place, without copying or checking them as UTF-8.
+pub(crate) fn depths(input: &[u8]) -> impl Iterator<Item = anyhow::Result<u64>> + '_ {
+    bytes::lines(input).map(parse_depth)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn to_depth_iter(input: impl BufRead) -> impl Iterator<Item = anyhow::Result<u64>> {
+    records(input, |depth| parse_depth(depth.as_bytes()))
+}
+
+pub fn inspect(input: &str) -> Report {
+    let mut report = Report::new();
+    let mut depths = vec![];
+
+    for (line, text) in numbered_lines(input) {
+        for depth in text.split_whitespace() {
+            match depth.parse::<u64>() {
+                Ok(depth) => depths.push(depth),
+                Err(_) => report.violation(line, format!("depth '{}' is not a number", depth)),
+            }
+        }
+    }
+
+    report.fact("Depths", depths.len());
+    if let Some((min, max)) = depths.iter().minmax().into_option() {
+        report.fact("Shallowest", min);
+        report.fact("Deepest", max);
+    }
+
+    report
+}
@@ -0,0 +1,84 @@
+use super::depths;
+use aoc_core::answer::Answer;
+use itertools::{process_results, Itertools};
+#[cfg(feature = "std")]
+use {super::to_depth_iter, std::io::BufRead};
+
+fn count_window_increases(depths: impl Iterator<Item = u64>) -> usize {
+    depths
+        .tuple_windows::<(_, _, _)>()
+        .map(|(a, b, c)| a + b + c)
+        .tuple_windows::<(_, _)>()
+        .map(|(a, b)| if a < b { 1 } else { 0 })
+        .sum()
+}
+
+fn solve_depths(depths: impl Iterator<Item = anyhow::Result<u64>>) -> anyhow::Result<Answer> {
+    let increases = process_results(depths, |depths| count_window_increases(depths))?;
+
+    Ok(u64::try_from(increases)?.into())
+}
+
+pub fn solve(input: &str) -> anyhow::Result<Answer> {
+    solve_bytes(input.as_bytes())
+}
+
+pub fn solve_bytes(input: &[u8]) -> anyhow::Result<Answer> {
+    solve_depths(depths(input))
+}
+
+/// Solves line by line, in constant memory.
+#[cfg(feature = "std")]
+pub fn stream(input: &mut dyn BufRead) -> anyhow::Result<Answer> {
+    solve_depths(to_depth_iter(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_01::{EXAMPLE_INPUT, PUZZLE_INPUT};
+    use crate::property::{check, vec_of};
+    use anyhow::ensure;
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let total_increases = process_results(to_depth_iter(EXAMPLE_INPUT.as_bytes()), |depths| {
+            count_window_increases(depths)
+        })?;
+
+        assert_eq!(total_increases, 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn puzzle() -> anyhow::Result<()> {
+        let total_increases = process_results(to_depth_iter(PUZZLE_INPUT.as_bytes()), |depths| {
+            count_window_increases(depths)
+        })?;
+
+        assert_eq!(total_increases, 1575);
+
+        Ok(())
+    }
+
+    #[test]
+    fn increasing_depths_always_increase_in_windows() {
+        check(
+            |rng| vec_of(rng, 100, |rng| rng.below(50)),
+            |steps| {
+                let depths: Vec<u64> = steps
+                    .iter()
+                    .scan(0, |depth, step| {
+                        *depth += step + 1;
+                        Some(*depth)
+                    })
+                    .collect();
+                let increases = count_window_increases(depths.iter().copied());
+
+                ensure!(increases == depths.len().saturating_sub(3));
+                Ok(())
+            },
+        );
+    }
+}
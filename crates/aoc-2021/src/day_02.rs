@@ -0,0 +1,151 @@
+pub mod part_01;
+pub mod part_02;
+
+use alloc::{format, string::ToString, vec::Vec};
+use anyhow::{anyhow, bail};
+use aoc_core::{
+    bytes,
+    inspect::{numbered_lines, Report},
+    visualise::{Colour, Frame, Visualise},
+};
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use {aoc_core::input::records, std::io::BufRead};
+
+pub const EXAMPLE_INPUT: &str = include_str!("../resources/day_02/example_input.txt");
+pub const PUZZLE_INPUT: &str = include_str!("../resources/day_02/puzzle_input.txt");
+pub const ANSWERS: &str = include_str!("../resources/day_02/answers.txt");
+
+const COURSE_WIDTH: usize = 72;
+const COURSE_HEIGHT: usize = 20;
+const COURSE_FRAMES: usize = 60;
+
+#[derive(Clone, Debug)]
+pub(crate) enum Command {
+    Forward(i64),
+    Down(i64),
+    Up(i64),
+}
+
+impl Command {
+    /// Parses a command such as `forward 5` in place.
+    fn parse(s: &[u8]) -> anyhow::Result<Self> {
+        use Command::{Down, Forward, Up};
+
+        let Some(space) = s.iter().position(|&b| b == b' ') else {
+            bail!("string is not a valid command: '{}'", bytes::show(s));
+        };
+        let (command, amount) = (&s[..space], &s[space + 1..]);
+        let parsed_amount = bytes::parse_i64(amount)
+            .ok_or_else(|| anyhow!("amount is invalid: '{}'", bytes::show(amount)))?;
+
+        let command = match command {
+            b"forward" => Forward(parsed_amount),
+            b"up" => Up(parsed_amount),
+            b"down" => Down(parsed_amount),
+            _ => bail!("command prefix is invalid: '{}'", bytes::show(command)),
+        };
+
+        Ok(command)
+    }
+}
+
+impl FromStr for Command {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.as_bytes())
+    }
+}
+
+/// Parses the commands in place, without copying or checking them as UTF-8.
+pub(crate) fn commands(input: &[u8]) -> impl Iterator<Item = anyhow::Result<Command>> + '_ {
+    bytes::lines(input).map(Command::parse)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn to_command_iter(
+    input: impl BufRead,
+) -> impl Iterator<Item = anyhow::Result<Command>> {
+    records(input, str::parse)
+}
+
+/// The first part of a submarine's course, plotted within the bounds of the
+/// whole course so that every frame shares the same scale.
+struct Course<'a> {
+    points: &'a [(i64, i64)],
+    min: (i64, i64),
+    max: (i64, i64),
+}
+
+impl Course<'_> {
+    fn scale(value: i64, min: i64, max: i64, size: usize) -> usize {
+        let range = (max - min).max(1);
+
+        ((value - min) * (size as i64 - 1) / range) as usize
+    }
+}
+
+impl Visualise for Course<'_> {
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::new(COURSE_WIDTH, COURSE_HEIGHT);
+
+        for (i, &(pos, depth)) in self.points.iter().enumerate() {
+            let x = Self::scale(pos, self.min.0, self.max.0, COURSE_WIDTH);
+            let y = Self::scale(depth, self.min.1, self.max.1, COURSE_HEIGHT);
+
+            if i + 1 == self.points.len() {
+                frame.set(x, y, '@', Colour::Yellow);
+            } else {
+                frame.set(x, y, '.', Colour::Cyan);
+            }
+        }
+
+        let (pos, depth) = self.points.last().copied().unwrap_or((0, 0));
+
+        frame.with_caption(format!("Position {}, depth {}", pos, depth))
+    }
+}
+
+/// Reveals a course, starting from the origin, over a fixed number of frames.
+fn plot_course(points: &[(i64, i64)]) -> Vec<Frame> {
+    let bounds = points
+        .iter()
+        .fold(((0, 0), (0, 0)), |(min, max), &(pos, depth)| {
+            (
+                (min.0.min(pos), min.1.min(depth)),
+                (max.0.max(pos), max.1.max(depth)),
+            )
+        });
+
+    (1..=COURSE_FRAMES)
+        .map(|i| Course {
+            points: &points[..points.len() * i / COURSE_FRAMES],
+            min: bounds.0,
+            max: bounds.1,
+        })
+        .map(|course| course.frame())
+        .collect()
+}
+
+pub fn inspect(input: &str) -> Report {
+    let mut report = Report::new();
+    let mut counts = [0; 3];
+
+    for (line, text) in numbered_lines(input) {
+        match text.parse::<Command>() {
+            Ok(Command::Forward(_)) => counts[0] += 1,
+            Ok(Command::Down(_)) => counts[1] += 1,
+            Ok(Command::Up(_)) => counts[2] += 1,
+            Err(e) => report.violation(line, e.to_string()),
+        }
+    }
+
+    report.fact("Commands", counts.iter().sum::<u64>());
+    report.histogram(
+        "Commands",
+        ["forward", "down", "up"].into_iter().zip(counts),
+    );
+
+    report
+}
@@ -0,0 +1,117 @@
+use super::{
+    commands, plot_course,
+    Command::{self, Down, Forward, Up},
+};
+use alloc::vec::Vec;
+use aoc_core::answer::Answer;
+use aoc_core::visualise::Frame;
+use itertools::process_results;
+#[cfg(feature = "std")]
+use {super::to_command_iter, std::io::BufRead};
+
+fn apply_command((pos, depth): (i64, i64), command: Command) -> (i64, i64) {
+    match command {
+        Forward(amount) => (pos + amount, depth),
+        Down(amount) => (pos, depth + amount),
+        Up(amount) => (pos, depth - amount),
+    }
+}
+
+fn apply_commands(coords: (i64, i64), commands: impl Iterator<Item = Command>) -> (i64, i64) {
+    commands.fold(coords, apply_command)
+}
+
+fn solve_commands(
+    commands: impl Iterator<Item = anyhow::Result<Command>>,
+) -> anyhow::Result<Answer> {
+    let (pos, depth) = process_results(commands, |commands| apply_commands((0, 0), commands))?;
+
+    Ok(u64::try_from(pos * depth)?.into())
+}
+
+pub fn solve(input: &str) -> anyhow::Result<Answer> {
+    solve_bytes(input.as_bytes())
+}
+
+pub fn solve_bytes(input: &[u8]) -> anyhow::Result<Answer> {
+    solve_commands(commands(input))
+}
+
+/// Solves line by line, in constant memory.
+#[cfg(feature = "std")]
+pub fn stream(input: &mut dyn BufRead) -> anyhow::Result<Answer> {
+    solve_commands(to_command_iter(input))
+}
+
+pub fn visualise(input: &str) -> anyhow::Result<Vec<Frame>> {
+    let commands: Vec<Command> = commands(input.as_bytes()).collect::<anyhow::Result<_>>()?;
+    let points: Vec<_> = commands
+        .into_iter()
+        .scan((0, 0), |coords, command| {
+            *coords = apply_command(*coords, command);
+            Some(*coords)
+        })
+        .collect();
+
+    Ok(plot_course(&points))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_02::{EXAMPLE_INPUT, PUZZLE_INPUT};
+    use crate::property::{check, vec_of};
+    use anyhow::ensure;
+    use aoc_core::generate::Rng;
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let (pos, depth) =
+            process_results(to_command_iter(EXAMPLE_INPUT.as_bytes()), |commands| {
+                apply_commands((0, 0), commands)
+            })?;
+        let coords_mul = pos * depth;
+
+        assert_eq!(coords_mul, 150);
+
+        Ok(())
+    }
+
+    #[test]
+    fn puzzle() -> anyhow::Result<()> {
+        let (pos, depth) = process_results(to_command_iter(PUZZLE_INPUT.as_bytes()), |commands| {
+            apply_commands((0, 0), commands)
+        })?;
+        let coords_mul = pos * depth;
+
+        assert_eq!(coords_mul, 1_936_494);
+
+        Ok(())
+    }
+
+    #[test]
+    fn command_order_does_not_matter() {
+        check(
+            |rng| {
+                let commands = vec_of(rng, 50, |rng| (rng.below(3), rng.range(0..=9)));
+                (commands, rng.next_u64())
+            },
+            |(commands, seed)| {
+                let mut commands: Vec<Command> = commands
+                    .iter()
+                    .map(|&(kind, amount)| match kind {
+                        0 => Forward(amount),
+                        1 => Down(amount),
+                        _ => Up(amount),
+                    })
+                    .collect();
+                let expected = apply_commands((0, 0), commands.clone().into_iter());
+
+                Rng::new(*seed).shuffle(&mut commands);
+
+                ensure!(apply_commands((0, 0), commands.into_iter()) == expected);
+                Ok(())
+            },
+        );
+    }
+}
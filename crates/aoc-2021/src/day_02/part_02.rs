@@ -0,0 +1,94 @@
+use super::{
+    commands, plot_course,
+    Command::{self, Down, Forward, Up},
+};
+use alloc::vec::Vec;
+use aoc_core::answer::Answer;
+use aoc_core::visualise::Frame;
+use itertools::process_results;
+#[cfg(feature = "std")]
+use {super::to_command_iter, std::io::BufRead};
+
+fn apply_command(((pos, depth), aim): ((i64, i64), i64), command: Command) -> ((i64, i64), i64) {
+    match command {
+        Forward(amount) => ((pos + amount, depth + aim * amount), aim),
+        Down(amount) => ((pos, depth), aim + amount),
+        Up(amount) => ((pos, depth), aim - amount),
+    }
+}
+
+fn apply_commands(
+    coords: (i64, i64),
+    aim: i64,
+    commands: impl Iterator<Item = Command>,
+) -> (i64, i64) {
+    let (coords, _) = commands.fold((coords, aim), apply_command);
+
+    coords
+}
+
+fn solve_commands(
+    commands: impl Iterator<Item = anyhow::Result<Command>>,
+) -> anyhow::Result<Answer> {
+    let (pos, depth) = process_results(commands, |commands| apply_commands((0, 0), 0, commands))?;
+
+    Ok(u64::try_from(pos * depth)?.into())
+}
+
+pub fn solve(input: &str) -> anyhow::Result<Answer> {
+    solve_bytes(input.as_bytes())
+}
+
+pub fn solve_bytes(input: &[u8]) -> anyhow::Result<Answer> {
+    solve_commands(commands(input))
+}
+
+/// Solves line by line, in constant memory.
+#[cfg(feature = "std")]
+pub fn stream(input: &mut dyn BufRead) -> anyhow::Result<Answer> {
+    solve_commands(to_command_iter(input))
+}
+
+pub fn visualise(input: &str) -> anyhow::Result<Vec<Frame>> {
+    let commands: Vec<Command> = commands(input.as_bytes()).collect::<anyhow::Result<_>>()?;
+    let points: Vec<_> = commands
+        .into_iter()
+        .scan(((0, 0), 0), |state, command| {
+            *state = apply_command(*state, command);
+            Some(state.0)
+        })
+        .collect();
+
+    Ok(plot_course(&points))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_02::{EXAMPLE_INPUT, PUZZLE_INPUT};
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let (pos, depth) =
+            process_results(to_command_iter(EXAMPLE_INPUT.as_bytes()), |commands| {
+                apply_commands((0, 0), 0, commands)
+            })?;
+        let coords_mul = pos * depth;
+
+        assert_eq!(coords_mul, 900);
+
+        Ok(())
+    }
+
+    #[test]
+    fn puzzle() -> anyhow::Result<()> {
+        let (pos, depth) = process_results(to_command_iter(PUZZLE_INPUT.as_bytes()), |commands| {
+            apply_commands((0, 0), 0, commands)
+        })?;
+        let coords_mul = pos * depth;
+
+        assert_eq!(coords_mul, 1_997_106_066);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+pub mod part_01;
+// pub mod part_02;
+
+use alloc::format;
+use aoc_core::inspect::{numbered_lines, Report};
+
+pub const EXAMPLE_INPUT: &str = include_str!("../resources/day_03/example_input.txt");
+pub const PUZZLE_INPUT: &str = include_str!("../resources/day_03/puzzle_input.txt");
+pub const ANSWERS: &str = include_str!("../resources/day_03/answers.txt");
+
+/// Checks that every line is a binary number as wide as the first.
+pub fn inspect(input: &str) -> Report {
+    let mut report = Report::new();
+    let mut width = None;
+    let mut lines = 0;
+
+    for (line, text) in numbered_lines(input) {
+        lines += 1;
+
+        let expected = *width.get_or_insert(text.len());
+        if text.len() != expected {
+            report.violation(
+                line,
+                format!("is {} bits wide, not {}", text.len(), expected),
+            );
+        }
+        if let Some(c) = text.chars().find(|c| !matches!(c, '0' | '1')) {
+            report.violation(line, format!("contains '{}', which isn't a bit", c));
+        }
+    }
+
+    report.fact("Lines", lines);
+    report.fact("Width", width.unwrap_or(0));
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inspect_reports_uneven_and_non_binary_lines() {
+        let report = inspect("00100\n1111\n10120\n");
+        let lines: Vec<usize> = report.violations().iter().map(|v| v.line).collect();
+
+        assert_eq!(lines, [2, 3]);
+    }
+}
@@ -0,0 +1,230 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use anyhow::{anyhow, bail};
+use aoc_core::answer::Answer;
+use aoc_core::explain::Explanation;
+
+struct Counter {
+    zeros: usize,
+    ones: usize,
+}
+
+impl Counter {
+    fn new() -> Self {
+        Self { zeros: 0, ones: 0 }
+    }
+
+    fn update(&mut self, c: char) -> anyhow::Result<()> {
+        match c {
+            '0' => self.zeros += 1,
+            '1' => self.ones += 1,
+            _ => bail!("char is not a binary digit: '{}'", c),
+        }
+
+        Ok(())
+    }
+
+    fn most_common(&self) -> anyhow::Result<char> {
+        use core::cmp::Ordering::{Equal, Greater, Less};
+
+        match self.zeros.cmp(&self.ones) {
+            Greater => Ok('0'),
+            Less => Ok('1'),
+            Equal => bail!("there is no most common char"),
+        }
+    }
+}
+
+struct Tracker(Vec<Counter>);
+
+impl Tracker {
+    fn new(cap: usize) -> Self {
+        let mut inner = Vec::with_capacity(cap);
+        for _ in 0..cap {
+            inner.push(Counter::new());
+        }
+
+        Self(inner)
+    }
+
+    /// Counts the bits in each column of every line.
+    fn tally(input: &str) -> anyhow::Result<Self> {
+        let line_len = input
+            .lines()
+            .next()
+            .ok_or_else(|| anyhow!("input must have at least one line"))?
+            .len();
+        let mut tracker = Self::new(line_len);
+
+        for line in input.lines() {
+            tracker.update(line)?;
+        }
+
+        Ok(tracker)
+    }
+
+    fn update(&mut self, s: &str) -> anyhow::Result<()> {
+        if s.len() != self.0.len() {
+            bail!("line must have {} bits: '{}'", self.0.len(), s);
+        }
+        for (i, c) in s.chars().enumerate() {
+            self.0[i].update(c)?;
+        }
+
+        Ok(())
+    }
+
+    fn finalise(self) -> anyhow::Result<String> {
+        let mut output = String::new();
+        for counter in self.0 {
+            output.push(counter.most_common()?);
+        }
+
+        Ok(output)
+    }
+}
+
+fn find_gamma(input: &str) -> anyhow::Result<String> {
+    Tracker::tally(input)?.finalise()
+}
+
+fn flip_bits(s: &str) -> String {
+    s.chars()
+        .map(|c| if c == '1' { '0' } else { '1' })
+        .collect()
+}
+
+fn calculate_power_consumption(gamma: &str) -> u64 {
+    let epsilon = flip_bits(gamma);
+
+    let gamma_num = u64::from_str_radix(gamma, 2).unwrap();
+    let epsilon_num = u64::from_str_radix(&epsilon, 2).unwrap();
+
+    gamma_num * epsilon_num
+}
+
+pub fn solve(input: &str) -> anyhow::Result<Answer> {
+    let gamma = find_gamma(input)?;
+
+    Ok(calculate_power_consumption(&gamma).into())
+}
+
+pub fn explain(input: &str) -> anyhow::Result<Explanation> {
+    let tracker = Tracker::tally(input)?;
+
+    let mut explanation = Explanation::new();
+    explanation.heading("Bit counts per column");
+    let rows = tracker
+        .0
+        .iter()
+        .enumerate()
+        .map(|(i, counter)| {
+            let gamma_bit = counter.most_common()?;
+            let epsilon_bit = flip_bits(&gamma_bit.to_string());
+
+            Ok([
+                (i + 1).to_string(),
+                counter.zeros.to_string(),
+                counter.ones.to_string(),
+                gamma_bit.to_string(),
+                epsilon_bit,
+            ])
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    explanation.table(
+        ["Column", "Zeros", "Ones", "Gamma bit", "Epsilon bit"],
+        rows,
+    );
+
+    let gamma = tracker.finalise()?;
+    let epsilon = flip_bits(&gamma);
+    let pc = calculate_power_consumption(&gamma);
+
+    explanation.heading("Power consumption");
+    explanation.step(format!(
+        "Gamma takes the most common bit of each column: `{}` = {}",
+        gamma,
+        u64::from_str_radix(&gamma, 2)?
+    ));
+    explanation.step(format!(
+        "Epsilon takes the least common bit, flipping gamma: `{}` = {}",
+        epsilon,
+        u64::from_str_radix(&epsilon, 2)?
+    ));
+    explanation.step(format!("Power consumption is gamma × epsilon = {}", pc));
+
+    Ok(explanation.answer(pc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_03::{EXAMPLE_INPUT, PUZZLE_INPUT};
+    use crate::property::{check, vec_of};
+    use anyhow::ensure;
+    use test_case::test_case;
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let gamma = find_gamma(EXAMPLE_INPUT)?;
+        let pc = calculate_power_consumption(&gamma);
+
+        assert_eq!(pc, 198);
+
+        Ok(())
+    }
+
+    #[test]
+    fn puzzle() -> anyhow::Result<()> {
+        let gamma = find_gamma(PUZZLE_INPUT)?;
+        let pc = calculate_power_consumption(&gamma);
+
+        assert_eq!(pc, 2_498_354);
+
+        Ok(())
+    }
+
+    #[test_case("" ; "empty")]
+    #[test_case("01\n12\n" ; "not binary")]
+    #[test_case("01\n10\n" ; "tied column")]
+    #[test_case("01\n011\n" ; "ragged")]
+    fn explaining_rejects_bad_input(input: &str) {
+        assert!(explain(input).is_err());
+        assert!(solve(input).is_err());
+    }
+
+    #[test]
+    fn gamma_and_epsilon_are_complementary() {
+        check(
+            |rng| vec_of(rng, 31, |rng| rng.below(2) == 1),
+            |bits| {
+                if bits.is_empty() {
+                    return Ok(());
+                }
+
+                let gamma: String = bits.iter().map(|&b| if b { '1' } else { '0' }).collect();
+                let epsilon = flip_bits(&gamma);
+                let gamma_num = u64::from_str_radix(&gamma, 2)?;
+                let epsilon_num = u64::from_str_radix(&epsilon, 2)?;
+
+                ensure!(
+                    gamma_num & epsilon_num == 0,
+                    "{} and {} overlap",
+                    gamma,
+                    epsilon
+                );
+                ensure!(
+                    gamma_num | epsilon_num == (1 << bits.len()) - 1,
+                    "{} and {} leave a bit unset",
+                    gamma,
+                    epsilon
+                );
+                ensure!(calculate_power_consumption(&gamma) == gamma_num * epsilon_num);
+                Ok(())
+            },
+        );
+    }
+}
@@ -0,0 +1,661 @@
+pub mod part_01;
+pub mod part_02;
+
+use nom::{
+    character::complete::{
+        char as parse_char, line_ending, multispace1, space0, space1, u8 as parse_u8,
+    },
+    combinator::{map, map_res},
+    multi::{count, separated_list1},
+    sequence::{delimited, separated_pair},
+    IResult,
+};
+
+use alloc::{collections::BTreeSet, format, string::String, vec, vec::Vec};
+use anyhow::anyhow;
+use aoc_core::{
+    inspect::Report,
+    visualise::{self, Colour, Frame, Visualise},
+};
+use core::{fmt, ops::Not};
+use itertools::Itertools;
+use tracing::{debug, trace};
+
+pub const EXAMPLE_INPUT: &str = include_str!("../resources/day_04/example_input.txt");
+pub const PUZZLE_INPUT: &str = include_str!("../resources/day_04/puzzle_input.txt");
+pub const ANSWERS: &str = include_str!("../resources/day_04/answers.txt");
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Cell {
+    val: u8,
+    marked: bool,
+}
+
+impl Cell {
+    fn new(val: u8) -> Self {
+        Self { val, marked: false }
+    }
+
+    #[cfg(test)]
+    fn new_marked(val: u8) -> Self {
+        Self { val, marked: true }
+    }
+
+    fn mark_if_equal(&mut self, number: u8) {
+        if self.val == number {
+            self.marked = true;
+        }
+    }
+}
+
+type Row = [Cell; 5];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Line {
+    Row(usize),
+    Column(usize),
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Row(idx) => write!(f, "row {}", idx + 1),
+            Self::Column(idx) => write!(f, "column {}", idx + 1),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Board {
+    rows: [Row; 5],
+}
+
+impl Board {
+    fn cells(&self) -> impl Iterator<Item = &Cell> {
+        self.rows.iter().flatten()
+    }
+
+    fn cells_mut(&mut self) -> impl Iterator<Item = &mut Cell> {
+        self.rows.iter_mut().flatten()
+    }
+
+    fn columns(&self) -> Vec<Vec<Cell>> {
+        let mut columns = vec![];
+        for _ in 0..5 {
+            let mut column = vec![];
+            for _ in 0..5 {
+                column.push(Cell::new(0));
+            }
+            columns.push(column);
+        }
+
+        for row_idx in 0..5 {
+            for (col_idx, column) in columns.iter_mut().enumerate() {
+                column[row_idx] = self.rows[row_idx][col_idx].clone();
+            }
+        }
+
+        columns
+    }
+
+    fn winning_line(&self) -> Option<Line> {
+        for (idx, row) in self.rows.iter().enumerate() {
+            if row.iter().all(|c| c.marked) {
+                return Some(Line::Row(idx));
+            }
+        }
+
+        for (idx, column) in self.columns().iter().enumerate() {
+            if column.iter().all(|c| c.marked) {
+                return Some(Line::Column(idx));
+            }
+        }
+
+        None
+    }
+
+    fn line_values(&self, line: Line) -> Vec<u8> {
+        match line {
+            Line::Row(idx) => self.rows[idx].iter().map(|c| c.val).collect(),
+            Line::Column(idx) => self.rows.iter().map(|row| row[idx].val).collect(),
+        }
+    }
+
+    fn has_won(&self) -> bool {
+        self.winning_line().is_some()
+    }
+
+    fn mark(&mut self, number: u8) {
+        for cell in self.cells_mut() {
+            cell.mark_if_equal(number);
+        }
+    }
+
+    fn unmarked_sum(&self) -> u64 {
+        self.cells()
+            .filter(|c| c.marked.not())
+            .map(|c| u64::from(c.val))
+            .sum()
+    }
+}
+
+/// Prints the board the way it appears in the puzzle input.
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.rows {
+            writeln!(
+                f,
+                "{}",
+                row.iter().map(|c| format!("{:>2}", c.val)).join(" ")
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Visualise for Board {
+    fn frame(&self) -> Frame {
+        let winning_line = self.winning_line();
+        let mut frame = Frame::new(14, 5);
+
+        for (y, row) in self.rows.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                let in_winning_line = match winning_line {
+                    Some(Line::Row(idx)) => idx == y,
+                    Some(Line::Column(idx)) => idx == x,
+                    None => false,
+                };
+                let colour = match (cell.marked, in_winning_line) {
+                    (true, true) => Colour::Yellow,
+                    (true, false) => Colour::Green,
+                    (false, _) => Colour::Dim,
+                };
+                frame.text(x * 3, y, &format!("{:>2}", cell.val), colour);
+            }
+        }
+
+        frame
+    }
+}
+
+#[derive(Debug)]
+struct Boards(Vec<Board>);
+
+impl Visualise for Boards {
+    fn frame(&self) -> Frame {
+        let frames: Vec<Frame> = self.0.iter().map(Board::frame).collect();
+
+        visualise::tile(&frames, 80, 2)
+    }
+}
+
+impl Boards {
+    fn mark_all(&mut self, number: u8) -> Option<Board> {
+        trace!(number, boards = self.0.len(), "draw");
+
+        for (idx, board) in self.0.iter_mut().enumerate() {
+            board.mark(number);
+            if board.has_won() {
+                debug!(
+                    board = idx,
+                    number,
+                    unmarked_sum = board.unmarked_sum(),
+                    "board won"
+                );
+                return Some(board.clone());
+            }
+        }
+
+        None
+    }
+}
+
+/// Plays bingo one draw per frame, until the first or the last board wins.
+fn animate(input: &str, until_last: bool) -> anyhow::Result<Vec<Frame>> {
+    let (_, (numbers, boards)) = bingo(input).map_err(|e| anyhow!("{}", e))?;
+    let mut boards = Boards(boards);
+    let mut frames = vec![boards.frame().with_caption("Ready to draw")];
+
+    for (draw, number) in numbers.into_iter().enumerate() {
+        for board in boards.0.iter_mut().filter(|b| b.has_won().not()) {
+            board.mark(number);
+        }
+
+        let winners = boards.0.iter().filter(|b| b.has_won()).count();
+        frames.push(boards.frame().with_caption(format!(
+            "Draw {}: {} ({} of {} boards have won)",
+            draw + 1,
+            number,
+            winners,
+            boards.0.len()
+        )));
+
+        if (until_last && winners == boards.0.len()) || (!until_last && winners > 0) {
+            break;
+        }
+    }
+
+    Ok(frames)
+}
+
+fn describe_win(draw: usize, number: u8, board_idx: usize, board: &Board, line: Line) -> String {
+    format!(
+        "Draw {} (`{}`) completes {} of board {}: `{}`",
+        draw + 1,
+        number,
+        line,
+        board_idx + 1,
+        board.line_values(line).iter().join(" ")
+    )
+}
+
+fn cell(input: &str) -> IResult<&str, Cell> {
+    map(parse_u8, Cell::new)(input)
+}
+
+fn row(input: &str) -> IResult<&str, Row> {
+    map_res(separated_list1(space1, cell), Row::try_from)(input)
+}
+
+pub(crate) fn board(input: &str) -> IResult<&str, Board> {
+    map(
+        map_res(
+            count(delimited(space0, row, line_ending), 5),
+            <[Row; 5]>::try_from,
+        ),
+        |rows| Board { rows },
+    )(input)
+}
+
+pub(crate) fn bingo(input: &str) -> IResult<&str, (Vec<u8>, Vec<Board>)> {
+    separated_pair(
+        separated_list1(parse_char(','), parse_u8),
+        multispace1,
+        separated_list1(multispace1, board),
+    )(input)
+}
+
+/// Each value that appears more than once, in the order they repeat.
+fn duplicates(values: &[u8]) -> Vec<u8> {
+    let mut seen = BTreeSet::new();
+    let mut repeated = BTreeSet::new();
+
+    values
+        .iter()
+        .filter(|&&v| !seen.insert(v) && repeated.insert(v))
+        .copied()
+        .collect()
+}
+
+/// Checks the draws and every board, which must be five rows of five numbers
+/// with none repeated.
+pub fn inspect(input: &str) -> Report {
+    let mut report = Report::new();
+    let mut lines = input.lines().enumerate().map(|(i, line)| (i + 1, line));
+
+    let draws: Vec<u8> = match lines.next() {
+        Some((line, text)) => text
+            .split(',')
+            .filter_map(|draw| match draw.trim().parse() {
+                Ok(draw) => Some(draw),
+                Err(_) => {
+                    report.violation(line, format!("draw '{}' is not a number", draw));
+                    None
+                }
+            })
+            .collect(),
+        None => vec![],
+    };
+    for draw in duplicates(&draws) {
+        report.violation(1, format!("{} is drawn more than once", draw));
+    }
+
+    let boards: Vec<Vec<(usize, &str)>> = lines
+        .collect::<Vec<_>>()
+        .split(|(_, text)| text.trim().is_empty())
+        .filter(|rows| !rows.is_empty())
+        .map(<[_]>::to_vec)
+        .collect();
+    let mut unique_boards = 0;
+
+    for (idx, rows) in boards.iter().enumerate() {
+        if rows.len() != 5 {
+            report.violation(
+                rows[0].0,
+                format!("board {} has {} rows, not 5", idx + 1, rows.len()),
+            );
+        }
+
+        let mut values = vec![];
+        for &(line, text) in rows {
+            let row: Vec<&str> = text.split_whitespace().collect();
+            if row.len() != 5 {
+                report.violation(line, format!("row has {} numbers, not 5", row.len()));
+            }
+            for value in row {
+                match value.parse::<u8>() {
+                    Ok(value) => values.push(value),
+                    Err(_) => report.violation(line, format!("'{}' is not a number", value)),
+                }
+            }
+        }
+
+        let repeated = duplicates(&values);
+        if repeated.is_empty() && values.len() == 25 {
+            unique_boards += 1;
+        }
+        for value in repeated {
+            report.violation(rows[0].0, format!("board {} repeats {}", idx + 1, value));
+        }
+    }
+
+    report.fact("Draws", draws.len());
+    report.fact(
+        "Every draw is unique",
+        if duplicates(&draws).is_empty() {
+            "yes"
+        } else {
+            "no"
+        },
+    );
+    report.fact("Boards", boards.len());
+    report.fact(
+        "Boards of 25 unique values",
+        format!("{} of {}", unique_boards, boards.len()),
+    );
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::check;
+    use anyhow::ensure;
+
+    #[test]
+    fn parse_board() -> anyhow::Result<()> {
+        let input = "22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+";
+        let expected = Board {
+            rows: [
+                [
+                    Cell::new(22),
+                    Cell::new(13),
+                    Cell::new(17),
+                    Cell::new(11),
+                    Cell::new(0),
+                ],
+                [
+                    Cell::new(8),
+                    Cell::new(2),
+                    Cell::new(23),
+                    Cell::new(4),
+                    Cell::new(24),
+                ],
+                [
+                    Cell::new(21),
+                    Cell::new(9),
+                    Cell::new(14),
+                    Cell::new(16),
+                    Cell::new(7),
+                ],
+                [
+                    Cell::new(6),
+                    Cell::new(10),
+                    Cell::new(3),
+                    Cell::new(18),
+                    Cell::new(5),
+                ],
+                [
+                    Cell::new(1),
+                    Cell::new(12),
+                    Cell::new(20),
+                    Cell::new(15),
+                    Cell::new(19),
+                ],
+            ],
+        };
+
+        let (_, board) = board(input)?;
+
+        assert_eq!(board, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cells_works() -> anyhow::Result<()> {
+        let input = "22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+";
+        let expected = vec![
+            Cell::new(22),
+            Cell::new(13),
+            Cell::new(17),
+            Cell::new(11),
+            Cell::new(0),
+            Cell::new(8),
+            Cell::new(2),
+            Cell::new(23),
+            Cell::new(4),
+            Cell::new(24),
+            Cell::new(21),
+            Cell::new(9),
+            Cell::new(14),
+            Cell::new(16),
+            Cell::new(7),
+            Cell::new(6),
+            Cell::new(10),
+            Cell::new(3),
+            Cell::new(18),
+            Cell::new(5),
+            Cell::new(1),
+            Cell::new(12),
+            Cell::new(20),
+            Cell::new(15),
+            Cell::new(19),
+        ];
+
+        let (_, board) = board(input)?;
+        let cells: Vec<Cell> = board.cells().cloned().collect();
+
+        assert_eq!(cells, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn hasnt_won() {
+        let board = Board {
+            rows: [
+                [
+                    Cell::new(22),
+                    Cell::new(13),
+                    Cell::new(17),
+                    Cell::new(11),
+                    Cell::new(0),
+                ],
+                [
+                    Cell::new(8),
+                    Cell::new(2),
+                    Cell::new(23),
+                    Cell::new(4),
+                    Cell::new(24),
+                ],
+                [
+                    Cell::new(21),
+                    Cell::new(9),
+                    Cell::new(14),
+                    Cell::new(16),
+                    Cell::new(7),
+                ],
+                [
+                    Cell::new(6),
+                    Cell::new(10),
+                    Cell::new(3),
+                    Cell::new(18),
+                    Cell::new(5),
+                ],
+                [
+                    Cell::new(1),
+                    Cell::new(12),
+                    Cell::new(20),
+                    Cell::new(15),
+                    Cell::new(19),
+                ],
+            ],
+        };
+
+        assert!(board.has_won().not());
+    }
+
+    #[test]
+    fn hasnt_won_row() {
+        let board = Board {
+            rows: [
+                [
+                    Cell::new(22),
+                    Cell::new(13),
+                    Cell::new(17),
+                    Cell::new(11),
+                    Cell::new(0),
+                ],
+                [
+                    Cell::new_marked(8),
+                    Cell::new_marked(2),
+                    Cell::new_marked(23),
+                    Cell::new_marked(4),
+                    Cell::new_marked(24),
+                ],
+                [
+                    Cell::new(21),
+                    Cell::new(9),
+                    Cell::new(14),
+                    Cell::new(16),
+                    Cell::new(7),
+                ],
+                [
+                    Cell::new(6),
+                    Cell::new(10),
+                    Cell::new(3),
+                    Cell::new(18),
+                    Cell::new(5),
+                ],
+                [
+                    Cell::new(1),
+                    Cell::new(12),
+                    Cell::new(20),
+                    Cell::new(15),
+                    Cell::new(19),
+                ],
+            ],
+        };
+
+        assert!(board.has_won());
+    }
+
+    #[test]
+    fn hasnt_won_column() {
+        let board = Board {
+            rows: [
+                [
+                    Cell::new(22),
+                    Cell::new(13),
+                    Cell::new_marked(17),
+                    Cell::new(11),
+                    Cell::new(0),
+                ],
+                [
+                    Cell::new(8),
+                    Cell::new(2),
+                    Cell::new_marked(23),
+                    Cell::new(4),
+                    Cell::new(24),
+                ],
+                [
+                    Cell::new(21),
+                    Cell::new(9),
+                    Cell::new_marked(14),
+                    Cell::new(16),
+                    Cell::new(7),
+                ],
+                [
+                    Cell::new(6),
+                    Cell::new(10),
+                    Cell::new_marked(3),
+                    Cell::new(18),
+                    Cell::new(5),
+                ],
+                [
+                    Cell::new(1),
+                    Cell::new(12),
+                    Cell::new_marked(20),
+                    Cell::new(15),
+                    Cell::new(19),
+                ],
+            ],
+        };
+
+        assert!(board.has_won());
+    }
+
+    #[test]
+    fn printed_board_parses_back() {
+        check(
+            |rng| [(); 25].map(|_| rng.below(256) as u8),
+            |values| {
+                let mut cells = values.iter().map(|&v| Cell::new(v));
+                let expected = Board {
+                    rows: [(); 5].map(|_| [(); 5].map(|_| cells.next().expect("25 values"))),
+                };
+
+                let printed = expected.to_string();
+                let (remainder, board) = board(&printed).map_err(|e| anyhow!("{}", e))?;
+
+                ensure!(
+                    board == expected,
+                    "parsed a different board from:\n{}",
+                    printed
+                );
+                ensure!(remainder.is_empty(), "left {:?} unparsed", remainder);
+                Ok(())
+            },
+        );
+    }
+
+    #[test]
+    fn inspect_reports_repeats_and_short_rows() {
+        let input = "7,4,9,7
+
+ 1  2  3  4  5
+ 6  7  8  9 10
+ 1 12 13 14
+16 17 18 19 20
+21 22 23 24 25
+";
+        let report = inspect(input);
+        let messages: Vec<(usize, &str)> = report
+            .violations()
+            .iter()
+            .map(|v| (v.line, v.message.as_str()))
+            .collect();
+
+        assert_eq!(
+            messages,
+            [
+                (1, "7 is drawn more than once"),
+                (5, "row has 4 numbers, not 5"),
+                (3, "board 1 repeats 1"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use super::{animate, bingo, describe_win, Boards};
+use alloc::{format, vec::Vec};
+use anyhow::anyhow;
+use aoc_core::answer::Answer;
+use aoc_core::{explain::Explanation, visualise::Frame};
+use itertools::Itertools;
+
+pub fn solve(input: &str) -> anyhow::Result<Answer> {
+    let (_, (numbers, boards)) = bingo(input).map_err(|e| anyhow!("{}", e))?;
+    let mut boards = Boards(boards);
+
+    for number in numbers {
+        if let Some(board) = boards.mark_all(number) {
+            return Ok((board.unmarked_sum() * u64::from(number)).into());
+        }
+    }
+
+    Err(anyhow!("no board won"))
+}
+
+pub fn explain(input: &str) -> anyhow::Result<Explanation> {
+    let (_, (numbers, mut boards)) = bingo(input).map_err(|e| anyhow!("{}", e))?;
+    let mut explanation = Explanation::new();
+
+    for (draw, &number) in numbers.iter().enumerate() {
+        for (idx, board) in boards.iter_mut().enumerate() {
+            board.mark(number);
+
+            if let Some(line) = board.winning_line() {
+                if draw > 0 {
+                    explanation.step(format!(
+                        "Draws 1 to {} (`{}`) complete no row or column",
+                        draw,
+                        numbers[..draw].iter().join(",")
+                    ));
+                }
+                explanation.step(describe_win(draw, number, idx, board, line));
+
+                let sum = board.unmarked_sum();
+                let score = sum * u64::from(number);
+                explanation.step(format!(
+                    "The unmarked numbers on board {} sum to {}, so its score is {} × {} = {}",
+                    idx + 1,
+                    sum,
+                    sum,
+                    number,
+                    score
+                ));
+
+                return Ok(explanation.answer(score));
+            }
+        }
+    }
+
+    Err(anyhow!("no board won"))
+}
+
+pub fn visualise(input: &str) -> anyhow::Result<Vec<Frame>> {
+    animate(input, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_04::{EXAMPLE_INPUT, PUZZLE_INPUT};
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let mut winning_board = None;
+        let mut winning_number = None;
+
+        let (_, (numbers, boards)) = bingo(EXAMPLE_INPUT)?;
+        let mut boards = Boards(boards);
+
+        'bingo: for number in numbers {
+            if let Some(board) = boards.mark_all(number) {
+                winning_board = Some(board);
+                winning_number = Some(u64::from(number));
+                break 'bingo;
+            };
+        }
+
+        let sum = winning_board
+            .expect("must have found a winning board")
+            .unmarked_sum();
+
+        assert_eq!(
+            sum * winning_number.expect("must have found a winning number"),
+            4512
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn puzzle() -> anyhow::Result<()> {
+        let mut winning_board = None;
+        let mut winning_number = None;
+
+        let (_, (numbers, boards)) = bingo(PUZZLE_INPUT)?;
+        let mut boards = Boards(boards);
+
+        'bingo: for number in numbers {
+            if let Some(board) = boards.mark_all(number) {
+                winning_board = Some(board);
+                winning_number = Some(u64::from(number));
+                break 'bingo;
+            };
+        }
+
+        let sum = winning_board
+            .expect("must have found a winning board")
+            .unmarked_sum();
+
+        assert_eq!(
+            sum * winning_number.expect("must have found a winning number"),
+            6592
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use super::{animate, bingo, describe_win, Board, Line};
+use alloc::{format, vec::Vec};
+use anyhow::anyhow;
+use aoc_core::answer::Answer;
+use aoc_core::{explain::Explanation, visualise::Frame};
+use core::ops::Not;
+use tracing::{debug, trace};
+
+/// Plays until a single board is left and it wins, calling `on_win` for
+/// every board that completes a line along the way. When the last boards all
+/// win on the same draw, none of them is the last winner.
+fn last_winner(
+    numbers: &[u8],
+    boards: Vec<Board>,
+    mut on_win: impl FnMut(usize, u8, usize, &Board, Line),
+) -> anyhow::Result<(usize, Board, u8)> {
+    let mut remaining: Vec<_> = boards.into_iter().enumerate().collect();
+
+    for (draw, &number) in numbers.iter().enumerate() {
+        trace!(number, boards = remaining.len(), "draw");
+
+        for (idx, board) in &mut remaining {
+            board.mark(number);
+
+            if let Some(line) = board.winning_line() {
+                debug!(
+                    board = *idx,
+                    number,
+                    unmarked_sum = board.unmarked_sum(),
+                    "board won"
+                );
+                on_win(draw, number, *idx, board, line);
+            }
+        }
+
+        if let [(idx, board)] = remaining.as_slice() {
+            if board.has_won() {
+                return Ok((*idx, board.clone(), number));
+            }
+        }
+
+        remaining.retain(|(_, board)| board.has_won().not());
+    }
+
+    Err(anyhow!("no losing board was found"))
+}
+
+pub fn solve(input: &str) -> anyhow::Result<Answer> {
+    let (_, (numbers, boards)) = bingo(input).map_err(|e| anyhow!("{}", e))?;
+    let (_, board, number) = last_winner(&numbers, boards, |_, _, _, _, _| {})?;
+
+    Ok((board.unmarked_sum() * u64::from(number)).into())
+}
+
+pub fn explain(input: &str) -> anyhow::Result<Explanation> {
+    let (_, (numbers, boards)) = bingo(input).map_err(|e| anyhow!("{}", e))?;
+    let mut explanation = Explanation::new();
+
+    let (idx, board, number) = last_winner(&numbers, boards, |draw, number, idx, board, line| {
+        explanation.step(describe_win(draw, number, idx, board, line));
+    })?;
+
+    let sum = board.unmarked_sum();
+    let score = sum * u64::from(number);
+    explanation.step(format!(
+        "Board {} is the last to win; its unmarked numbers sum to {}, so its score is {} × {} = {}",
+        idx + 1,
+        sum,
+        sum,
+        number,
+        score
+    ));
+
+    Ok(explanation.answer(score))
+}
+
+pub fn visualise(input: &str) -> anyhow::Result<Vec<Frame>> {
+    animate(input, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_04::{EXAMPLE_INPUT, PUZZLE_INPUT};
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let (_, (numbers, boards)) = bingo(EXAMPLE_INPUT)?;
+        let (_, board, number) = last_winner(&numbers, boards, |_, _, _, _, _| {})?;
+
+        assert_eq!(board.unmarked_sum() * u64::from(number), 1924);
+
+        Ok(())
+    }
+
+    #[test]
+    fn puzzle() -> anyhow::Result<()> {
+        let (_, (numbers, boards)) = bingo(PUZZLE_INPUT)?;
+        let (_, board, number) = last_winner(&numbers, boards, |_, _, _, _, _| {})?;
+
+        assert_eq!(board.unmarked_sum() * u64::from(number), 31755);
+
+        Ok(())
+    }
+
+    #[test]
+    fn explain_agrees_when_the_last_boards_win_together() {
+        let input = "1,2,3,4,5,6,7,8,9,10\n\n\
+                      1  2  3  4 10\n\
+                     11 12 13 14 15\n\
+                     16 17 18 19 20\n\
+                     21 22 23 24 25\n\
+                     26 27 28 29 30\n\n\
+                      6  7  8  9 10\n\
+                     31 32 33 34  5\n\
+                     35 36 37 38 39\n\
+                     40 41 42 43 44\n\
+                     45 46 47 48 49\n";
+
+        let solved = solve(input).unwrap_err();
+        let explained = explain(input).map(|_| ()).unwrap_err();
+
+        assert_eq!(solved.to_string(), "no losing board was found");
+        assert_eq!(explained.to_string(), solved.to_string());
+    }
+}
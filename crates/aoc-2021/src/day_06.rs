@@ -0,0 +1,60 @@
+pub mod part_01;
+pub mod part_02;
+
+use alloc::{format, vec::Vec};
+use anyhow::anyhow;
+use aoc_core::{
+    bytes,
+    inspect::{comma_separated, Report},
+};
+
+pub const EXAMPLE_INPUT: &str = include_str!("../resources/day_06/example_input.txt");
+pub const PUZZLE_INPUT: &str = include_str!("../resources/day_06/puzzle_input.txt");
+pub const ANSWERS: &str = include_str!("../resources/day_06/answers.txt");
+
+/// Parses comma-separated timers in place, which a fish only ever counts down
+/// from 8.
+fn timers(input: &[u8]) -> impl Iterator<Item = anyhow::Result<u8>> + '_ {
+    bytes::fields(input, b',').map(|timer| match bytes::parse_u64(timer) {
+        Some(timer @ 0..=8) => Ok(timer as u8),
+        _ => Err(anyhow!("timer is invalid: '{}'", bytes::show(timer))),
+    })
+}
+
+pub(crate) fn parse_timers(input: &str) -> anyhow::Result<Vec<u8>> {
+    timers(input.as_bytes()).collect()
+}
+
+/// How many fish have each timer, without collecting the timers first.
+pub(crate) fn count_timers(input: &[u8]) -> anyhow::Result<[u64; 9]> {
+    let mut counts = [0; 9];
+    for timer in timers(input) {
+        counts[usize::from(timer?)] += 1;
+    }
+
+    Ok(counts)
+}
+
+pub fn inspect(input: &str) -> Report {
+    let mut report = Report::new();
+    let mut counts = [0; 9];
+
+    for (line, timer) in comma_separated(input) {
+        match timer.parse::<usize>() {
+            Ok(timer) if timer < counts.len() => counts[timer] += 1,
+            Ok(_) => report.violation(line, format!("timer {} is greater than 8", timer)),
+            Err(_) => report.violation(line, format!("timer '{}' is not a number", timer)),
+        }
+    }
+
+    report.fact("Fish", counts.iter().sum::<u64>());
+    if let Some(min) = counts.iter().position(|&c| c > 0) {
+        report.fact("Lowest timer", min);
+    }
+    if let Some(max) = counts.iter().rposition(|&c| c > 0) {
+        report.fact("Highest timer", max);
+    }
+    report.histogram("Timers", counts.into_iter().enumerate());
+
+    report
+}
@@ -1,4 +1,7 @@
-use super::{EXAMPLE_INPUT, PUZZLE_INPUT};
+use super::parse_timers;
+use alloc::vec::Vec;
+use aoc_core::answer::Answer;
+use tracing::trace;
 
 struct LanternFish {
     timer: i8,
@@ -9,8 +12,8 @@ impl LanternFish {
         Self { timer: 8 }
     }
 
-    fn from_timer(timer: i8) -> Self {
-        Self { timer }
+    fn from_timer(timer: u8) -> Self {
+        Self { timer: timer as i8 }
     }
 
     fn tick(&mut self) -> Option<LanternFish> {
@@ -26,21 +29,31 @@ impl LanternFish {
     }
 }
 
-fn parse_timers(input: &str) -> Vec<i8> {
-    input
-        .trim()
-        .split(',')
-        .map(|timer| timer.parse().expect("must be valid i8"))
-        .collect()
+pub(crate) fn population_after(input: &str, days: usize) -> anyhow::Result<u64> {
+    let timers = parse_timers(input)?;
+    let mut fish: Vec<LanternFish> = timers.into_iter().map(LanternFish::from_timer).collect();
+
+    for day in 0..days {
+        let mut new_fish = fish.iter_mut().filter_map(LanternFish::tick).collect();
+        fish.append(&mut new_fish);
+        trace!(day, population = fish.len(), "day simulated");
+    }
+
+    Ok(u64::try_from(fish.len())?)
+}
+
+pub fn solve(input: &str) -> anyhow::Result<Answer> {
+    Ok(population_after(input, 80)?.into())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day_06::{EXAMPLE_INPUT, PUZZLE_INPUT};
 
     #[test]
-    fn example() {
-        let timers = parse_timers(EXAMPLE_INPUT);
+    fn example() -> anyhow::Result<()> {
+        let timers = parse_timers(EXAMPLE_INPUT)?;
         let mut fish: Vec<LanternFish> = timers.into_iter().map(LanternFish::from_timer).collect();
 
         for _ in 0..18 {
@@ -56,11 +69,13 @@ mod tests {
         }
 
         assert_eq!(fish.len(), 5934);
+
+        Ok(())
     }
 
     #[test]
-    fn puzzle() {
-        let timers = parse_timers(PUZZLE_INPUT);
+    fn puzzle() -> anyhow::Result<()> {
+        let timers = parse_timers(PUZZLE_INPUT)?;
         let mut fish: Vec<LanternFish> = timers.into_iter().map(LanternFish::from_timer).collect();
 
         for _ in 0..80 {
@@ -69,5 +84,7 @@ mod tests {
         }
 
         assert_eq!(fish.len(), 383_160);
+
+        Ok(())
     }
 }
@@ -0,0 +1,290 @@
+use super::count_timers;
+#[cfg(test)]
+use super::parse_timers;
+use alloc::{format, string::ToString, vec, vec::Vec};
+use aoc_core::answer::Answer;
+use aoc_core::visualise::{Colour, Frame, Visualise};
+use tracing::trace;
+
+const HISTOGRAM_HEIGHT: usize = 16;
+
+/// Lantern fish counted by the day they next spawn on, rather than one by one.
+pub struct School {
+    adults: [u64; 7],
+    juveniles: [u64; 9],
+    days_elapsed: usize,
+}
+
+impl School {
+    #[cfg(test)]
+    fn new(timers: Vec<u8>) -> Self {
+        let mut counts = [0; 9];
+        for timer in timers {
+            counts[usize::from(timer)] += 1;
+        }
+
+        Self::from_counts(counts)
+    }
+
+    /// A school with `counts[t]` fish whose timer is `t`.
+    fn from_counts(counts: [u64; 9]) -> Self {
+        let mut adults = [0; 7];
+        let mut juveniles = [0; 9];
+        for (timer, count) in counts.into_iter().enumerate() {
+            // A fish with a timer of 7 or 8 hasn't spawned yet, but it will
+            // on the same day as a juvenile would.
+            match adults.get_mut(timer) {
+                Some(adults) => *adults += count,
+                None => juveniles[timer] += count,
+            }
+        }
+
+        Self {
+            adults,
+            juveniles,
+            days_elapsed: 0,
+        }
+    }
+
+    pub fn parse(input: &str) -> anyhow::Result<Self> {
+        Self::parse_bytes(input.as_bytes())
+    }
+
+    /// Counts the fish in place, without collecting their timers.
+    pub fn parse_bytes(input: &[u8]) -> anyhow::Result<Self> {
+        Ok(Self::from_counts(count_timers(input)?))
+    }
+
+    /// Simulates one more day.
+    pub fn step(&mut self) {
+        self.spawn(self.days_elapsed);
+    }
+
+    pub fn days_elapsed(&self) -> usize {
+        self.days_elapsed
+    }
+
+    fn spawn(&mut self, day: usize) {
+        let adult_group = day % 7;
+        let juvenile_group = day % 9;
+
+        let new_adults = self.juveniles[juvenile_group];
+        let new_juveniles = self.adults[adult_group] + self.juveniles[juvenile_group];
+
+        self.juveniles[juvenile_group] = new_juveniles;
+        self.adults[adult_group] += new_adults;
+        self.days_elapsed = day + 1;
+
+        trace!(day, population = self.total_fish(), "day simulated");
+    }
+
+    pub fn total_fish(&self) -> u64 {
+        self.adults.iter().chain(self.juveniles.iter()).sum()
+    }
+
+    /// The number of fish with each timer value at the start of the next day.
+    ///
+    /// The groups are indexed by the day they next spawn on, rather than by
+    /// timer, so they have to be rotated by the number of days elapsed.
+    pub fn timers(&self) -> [u64; 9] {
+        let mut timers = [0; 9];
+        for (timer, count) in timers.iter_mut().enumerate() {
+            let day = self.days_elapsed + timer;
+            if timer < 7 {
+                *count += self.adults[day % 7];
+            }
+            *count += self.juveniles[day % 9];
+        }
+
+        timers
+    }
+}
+
+impl Visualise for School {
+    fn frame(&self) -> Frame {
+        let timers = self.timers();
+        let max = timers.iter().copied().max().unwrap_or(0).max(1);
+        let mut frame = Frame::new(timers.len() * 4, HISTOGRAM_HEIGHT + 1);
+
+        for (timer, &count) in timers.iter().enumerate() {
+            let bar = (count * HISTOGRAM_HEIGHT as u64).div_ceil(max) as usize;
+            let colour = if timer == 0 {
+                Colour::Red
+            } else {
+                Colour::Cyan
+            };
+
+            for y in HISTOGRAM_HEIGHT - bar..HISTOGRAM_HEIGHT {
+                frame.text(timer * 4, y, "███", colour);
+            }
+            frame.text(
+                timer * 4 + 1,
+                HISTOGRAM_HEIGHT,
+                &timer.to_string(),
+                Colour::Plain,
+            );
+        }
+
+        frame.with_caption(format!(
+            "Day {}: {} fish",
+            self.days_elapsed,
+            self.total_fish()
+        ))
+    }
+}
+
+pub(crate) fn population_after(input: &str, days: usize) -> anyhow::Result<u64> {
+    population_after_bytes(input.as_bytes(), days)
+}
+
+fn population_after_bytes(input: &[u8], days: usize) -> anyhow::Result<u64> {
+    let mut school = School::parse_bytes(input)?;
+
+    for day in 0..days {
+        school.spawn(day);
+    }
+
+    Ok(school.total_fish())
+}
+
+pub fn solve(input: &str) -> anyhow::Result<Answer> {
+    Ok(population_after(input, 256)?.into())
+}
+
+pub fn solve_bytes(input: &[u8]) -> anyhow::Result<Answer> {
+    Ok(population_after_bytes(input, 256)?.into())
+}
+
+/// The population at the start, then after each of `days` days.
+pub fn populations(input: &str, days: usize) -> anyhow::Result<Vec<u64>> {
+    let mut school = School::parse(input)?;
+    let mut populations = vec![school.total_fish()];
+
+    for _ in 0..days {
+        school.step();
+        populations.push(school.total_fish());
+    }
+
+    Ok(populations)
+}
+
+pub fn visualise(input: &str) -> anyhow::Result<Vec<Frame>> {
+    let mut school = School::parse(input)?;
+    let mut frames = vec![school.frame()];
+
+    for day in 0..256 {
+        school.spawn(day);
+        frames.push(school.frame());
+    }
+
+    Ok(frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_06::{EXAMPLE_INPUT, PUZZLE_INPUT};
+    use crate::property::{check, vec_of};
+    use anyhow::ensure;
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let timers = parse_timers(EXAMPLE_INPUT)?;
+        let mut school = School::new(timers);
+
+        for day in 0..18 {
+            school.spawn(day);
+        }
+
+        assert_eq!(school.total_fish(), 26);
+
+        for day in 18..80 {
+            school.spawn(day);
+        }
+
+        assert_eq!(school.total_fish(), 5934);
+
+        for day in 80..256 {
+            school.spawn(day);
+        }
+
+        assert_eq!(school.total_fish(), 26_984_457_539);
+
+        Ok(())
+    }
+
+    #[test]
+    fn puzzle() -> anyhow::Result<()> {
+        let timers = parse_timers(PUZZLE_INPUT)?;
+        let mut school = School::new(timers);
+
+        for day in 0..256 {
+            school.spawn(day);
+        }
+
+        assert_eq!(school.total_fish(), 1_721_148_811_504);
+
+        Ok(())
+    }
+
+    #[test]
+    fn populations_follow_the_example() -> anyhow::Result<()> {
+        let populations = populations(EXAMPLE_INPUT, 80)?;
+
+        assert_eq!(populations.len(), 81);
+        assert_eq!(populations[0], 5);
+        assert_eq!(populations[18], 26);
+        assert_eq!(populations[80], 5934);
+
+        Ok(())
+    }
+
+    #[test]
+    fn timers_follow_the_example() -> anyhow::Result<()> {
+        let timers = parse_timers(EXAMPLE_INPUT)?;
+        let mut school = School::new(timers);
+
+        assert_eq!(school.timers(), [0, 1, 1, 2, 1, 0, 0, 0, 0]);
+
+        for day in 0..3 {
+            school.spawn(day);
+        }
+
+        // After 3 days: 0,1,0,5,6,7,8
+        assert_eq!(school.timers(), [2, 1, 0, 0, 0, 1, 1, 1, 1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn schools_grow_independently() {
+        check(
+            |rng| {
+                let timers = vec_of(rng, 20, |rng| rng.below(9) as u8);
+                let split = rng.below(timers.len() as u64 + 1) as usize;
+                (timers, (split, rng.below(100) as usize))
+            },
+            |(timers, (split, days))| {
+                let split = (*split).min(timers.len());
+                let simulate = |timers: &[u8]| {
+                    let mut school = School::new(timers.to_vec());
+                    for day in 0..*days {
+                        school.spawn(day);
+                    }
+                    school.total_fish()
+                };
+
+                let whole = simulate(timers);
+                let parts = simulate(&timers[..split]) + simulate(&timers[split..]);
+
+                ensure!(
+                    whole == parts,
+                    "{} fish in the whole, {} in its parts",
+                    whole,
+                    parts
+                );
+                Ok(())
+            },
+        );
+    }
+}
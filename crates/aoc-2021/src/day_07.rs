@@ -0,0 +1,72 @@
+pub mod part_01;
+pub mod part_02;
+
+use alloc::{format, vec};
+use anyhow::anyhow;
+use aoc_core::{
+    bytes,
+    inspect::{comma_separated, Report},
+};
+
+pub const EXAMPLE_INPUT: &str = include_str!("../resources/day_07/example_input.txt");
+pub const PUZZLE_INPUT: &str = include_str!("../resources/day_07/puzzle_input.txt");
+pub const ANSWERS: &str = include_str!("../resources/day_07/answers.txt");
+
+/// Parses comma-separated positions in place, without copying them.
+pub(crate) fn positions(input: &[u8]) -> impl Iterator<Item = anyhow::Result<i64>> + '_ {
+    bytes::fields(input, b',').map(|position| {
+        bytes::parse_i64(position)
+            .ok_or_else(|| anyhow!("position is invalid: '{}'", bytes::show(position)))
+    })
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn parse_positions(input: &str) -> anyhow::Result<Vec<i64>> {
+    positions(input.as_bytes()).collect()
+}
+
+/// How many equal-width ranges the positions are grouped into.
+const HISTOGRAM_BUCKETS: i64 = 10;
+
+pub fn inspect(input: &str) -> Report {
+    let mut report = Report::new();
+    let mut positions = vec![];
+
+    for (line, position) in comma_separated(input) {
+        match position.parse::<i64>() {
+            Ok(position) => positions.push(position),
+            Err(_) => report.violation(line, format!("position '{}' is not a number", position)),
+        }
+    }
+    positions.sort_unstable();
+
+    report.fact("Crabs", positions.len());
+    if let (Some(&min), Some(&max)) = (positions.first(), positions.last()) {
+        report.fact("Leftmost", min);
+        report.fact("Rightmost", max);
+        report.fact("Median", positions[positions.len() / 2]);
+        report.fact(
+            "Mean",
+            format!(
+                "{:.1}",
+                positions.iter().sum::<i64>() as f64 / positions.len() as f64
+            ),
+        );
+
+        let step = ((max - min) / HISTOGRAM_BUCKETS + 1).max(1);
+        let buckets = (0..HISTOGRAM_BUCKETS).map_while(|i| {
+            let start = min + i * step;
+            (start <= max).then(|| {
+                let end = start + step - 1;
+                let count = positions
+                    .iter()
+                    .filter(|&&p| (start..=end).contains(&p))
+                    .count();
+                (format!("{}–{}", start, end), count as u64)
+            })
+        });
+        report.histogram("Positions", buckets);
+    }
+
+    report
+}
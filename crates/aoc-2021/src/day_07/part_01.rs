@@ -0,0 +1,157 @@
+#[cfg(feature = "std")]
+use super::parse_positions;
+use super::positions;
+use alloc::vec::Vec;
+use anyhow::anyhow;
+use aoc_core::answer::Answer;
+use tracing::trace;
+
+fn total_fuel(positions: &[i64], target: i64) -> i64 {
+    positions.iter().map(|p| (p - target).abs()).sum()
+}
+
+/// The median minimises the total distance: moving away from it takes at
+/// least as many crabs further away as it brings closer. It's found by
+/// partially reordering the positions, rather than sorting them.
+fn cheapest_target(positions: &mut [i64]) -> Option<i64> {
+    if positions.is_empty() {
+        return None;
+    }
+    let middle = positions.len() / 2;
+
+    Some(*positions.select_nth_unstable(middle).1)
+}
+
+pub fn solve(input: &str) -> anyhow::Result<Answer> {
+    solve_bytes(input.as_bytes())
+}
+
+pub fn solve_bytes(input: &[u8]) -> anyhow::Result<Answer> {
+    let mut positions: Vec<i64> = positions(input).collect::<anyhow::Result<_>>()?;
+    let target = cheapest_target(&mut positions)
+        .ok_or_else(|| anyhow!("must have at least one position"))?;
+    let fuel = total_fuel(&positions, target);
+    trace!(position = target, fuel, "candidate");
+
+    Ok(u64::try_from(fuel)?.into())
+}
+
+/// Tries every position between the outermost crabs, as a reference for
+/// `solve`.
+#[cfg(feature = "std")]
+pub(crate) fn solve_by_scan(input: &str) -> anyhow::Result<Answer> {
+    let positions = parse_positions(input)?;
+    let min = *positions
+        .iter()
+        .min()
+        .ok_or_else(|| anyhow!("must have at least one position"))?;
+    let max = *positions
+        .iter()
+        .max()
+        .ok_or_else(|| anyhow!("must have at least one position"))?;
+
+    let best = (min..=max)
+        .map(|target| total_fuel(&positions, target))
+        .min()
+        .ok_or_else(|| anyhow!("must have at least one candidate"))?;
+
+    Ok(u64::try_from(best)?.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_07::{EXAMPLE_INPUT, PUZZLE_INPUT};
+    use crate::property::{check, vec_of};
+    use alloc::vec;
+    use anyhow::ensure;
+    use itertools::Itertools;
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let positions = parse_positions(EXAMPLE_INPUT)?;
+        let num_crabs = positions.len();
+
+        let total: i64 = positions.iter().sum();
+        let mean = total / num_crabs as i64;
+
+        let mut candidates = vec![];
+        for i in 0..(num_crabs / 3) {
+            let pos_total_fuel: i64 = positions.iter().map(|p| (p - mean - i as i64).abs()).sum();
+            let neg_total_fuel: i64 = positions.iter().map(|p| (p - mean + i as i64).abs()).sum();
+
+            candidates.push(pos_total_fuel);
+            candidates.push(neg_total_fuel);
+        }
+
+        let best = *candidates.iter().min().expect("must have candidates");
+
+        assert_eq!(best, 37);
+
+        Ok(())
+    }
+
+    #[test]
+    fn puzzle() -> anyhow::Result<()> {
+        let positions = parse_positions(PUZZLE_INPUT)?;
+        let num_crabs = positions.len();
+
+        let total: i64 = positions.iter().sum();
+        let mean = total / num_crabs as i64;
+
+        let mut candidates = vec![];
+        for i in 0..(num_crabs / 3) {
+            let pos_total_fuel: i64 = positions.iter().map(|p| (p - mean - i as i64).abs()).sum();
+            let neg_total_fuel: i64 = positions.iter().map(|p| (p - mean + i as i64).abs()).sum();
+
+            candidates.push(pos_total_fuel);
+            candidates.push(neg_total_fuel);
+        }
+
+        let best = *candidates.iter().min().expect("must have candidates");
+
+        assert_eq!(best, 355_764);
+
+        Ok(())
+    }
+
+    /// A window of a third of the crabs either side of the mean found
+    /// nothing for one or two crabs, and missed a median far from the mean.
+    #[test]
+    fn finds_the_optimum_for_a_few_crabs() -> anyhow::Result<()> {
+        assert_eq!(solve("1")?, 0);
+        assert_eq!(solve("0,10")?, 10);
+        assert_eq!(solve("0,0,0,9")?, 9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn optimum_beats_every_sampled_target() {
+        check(
+            |rng| {
+                let positions = vec_of(rng, 30, |rng| rng.below(200));
+                (positions, rng.below(200))
+            },
+            |(positions, target)| {
+                if positions.is_empty() {
+                    return Ok(());
+                }
+
+                let positions: Vec<i64> = positions.iter().map(|&p| p as i64).collect();
+                let target = *target as i64;
+                let optimum = solve(&positions.iter().join(","))?;
+                let fuel = total_fuel(&positions, target);
+
+                ensure!(
+                    optimum.as_i128().is_some_and(|o| o <= i128::from(fuel)),
+                    "moving to {} costs only {}, not {}",
+                    target,
+                    fuel,
+                    optimum
+                );
+                Ok(())
+            },
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use super::positions;
+use alloc::{vec, vec::Vec};
+use anyhow::anyhow;
+#[cfg(feature = "std")]
+use anyhow::bail;
+use aoc_core::answer::Answer;
+use tracing::trace;
+
+fn fuel_used(from: i64, to: i64) -> i64 {
+    let distance = (from - to).abs();
+
+    (1..=distance).sum()
+}
+
+pub fn solve(input: &str) -> anyhow::Result<Answer> {
+    let positions: Vec<i64> = positions(input.as_bytes()).collect::<anyhow::Result<_>>()?;
+    let min = *positions
+        .iter()
+        .min()
+        .ok_or_else(|| anyhow!("must have at least one position"))?;
+    let max = *positions
+        .iter()
+        .max()
+        .ok_or_else(|| anyhow!("must have at least one position"))?;
+
+    let mut candidates = vec![];
+    for new_pos in min..=max {
+        let fuel: i64 = positions
+            .iter()
+            .map(|crab_pos| fuel_used(*crab_pos, new_pos))
+            .sum();
+        trace!(position = new_pos, fuel, "candidate");
+        candidates.push(fuel);
+    }
+
+    let best = *candidates
+        .iter()
+        .min()
+        .ok_or_else(|| anyhow!("must have at least one candidate"))?;
+
+    Ok(u64::try_from(best)?.into())
+}
+
+/// Checks only the positions around the mean, as an optimised counterpart
+/// to `solve`. The total fuel is convex, and its real-valued minimum lies
+/// within half a step of the mean, so the best whole position is at most one
+/// either side of the mean's neighbours. Two passes over the input, one for
+/// the mean and one for the candidates' fuel, mean the positions are never
+/// collected.
+#[cfg(feature = "std")]
+pub(crate) fn solve_near_mean(input: &str) -> anyhow::Result<Answer> {
+    let input = input.as_bytes();
+    let (mut sum, mut num_crabs) = (0i64, 0i64);
+    for position in positions(input) {
+        sum += position?;
+        num_crabs += 1;
+    }
+    if num_crabs == 0 {
+        bail!("must have at least one position");
+    }
+    let mean = sum.div_euclid(num_crabs);
+
+    let mut candidates = [0; 4];
+    for position in positions(input) {
+        let position = position?;
+        for (fuel, new_pos) in candidates.iter_mut().zip(mean - 1..) {
+            *fuel += fuel_used(position, new_pos);
+        }
+    }
+    for (fuel, new_pos) in candidates.iter().zip(mean - 1..) {
+        trace!(position = new_pos, fuel, "candidate");
+    }
+
+    let best = *candidates
+        .iter()
+        .min()
+        .ok_or_else(|| anyhow!("must have at least one candidate"))?;
+
+    Ok(u64::try_from(best)?.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_07::{parse_positions, EXAMPLE_INPUT, PUZZLE_INPUT};
+    use crate::property::{check, vec_of};
+    use anyhow::ensure;
+    use itertools::Itertools;
+    use test_case::test_case;
+
+    #[test_case(16, 5, 66)]
+    #[test_case(1, 5, 10)]
+    #[test_case(2, 5, 6)]
+    #[test_case(0, 5, 15)]
+    #[test_case(4, 5, 1)]
+    #[test_case(7, 5, 3)]
+    #[test_case(14, 5, 45)]
+    fn fuel_used_works(from: i64, to: i64, expected: i64) {
+        let fuel = fuel_used(from, to);
+
+        assert_eq!(fuel, expected);
+    }
+
+    #[test]
+    fn example() -> anyhow::Result<()> {
+        let positions = parse_positions(EXAMPLE_INPUT)?;
+        let min = *positions
+            .iter()
+            .min()
+            .expect("must have at least one position");
+        let max = *positions
+            .iter()
+            .max()
+            .expect("must have at least one position");
+
+        let mut candidates = vec![];
+        for new_pos in min..=max {
+            let fuel: i64 = positions
+                .iter()
+                .map(|crab_pos| fuel_used(*crab_pos, new_pos))
+                .sum();
+            candidates.push(fuel);
+        }
+
+        let best = *candidates
+            .iter()
+            .min()
+            .expect("must have at least one candidate");
+
+        assert_eq!(best, 168);
+
+        Ok(())
+    }
+
+    #[test]
+    fn puzzle() -> anyhow::Result<()> {
+        let positions = parse_positions(PUZZLE_INPUT)?;
+        let min = *positions
+            .iter()
+            .min()
+            .expect("must have at least one position");
+        let max = *positions
+            .iter()
+            .max()
+            .expect("must have at least one position");
+
+        let mut candidates = vec![];
+        for new_pos in min..=max {
+            let fuel: i64 = positions
+                .iter()
+                .map(|crab_pos| fuel_used(*crab_pos, new_pos))
+                .sum();
+            candidates.push(fuel);
+        }
+
+        let best = *candidates
+            .iter()
+            .min()
+            .expect("must have at least one candidate");
+
+        assert_eq!(best, 99_634_572);
+
+        Ok(())
+    }
+
+    #[test]
+    fn optimum_beats_every_sampled_target() {
+        check(
+            |rng| {
+                let positions = vec_of(rng, 30, |rng| rng.below(200));
+                (positions, rng.below(200))
+            },
+            |(positions, target)| {
+                if positions.is_empty() {
+                    return Ok(());
+                }
+
+                let positions: Vec<i64> = positions.iter().map(|&p| p as i64).collect();
+                let target = *target as i64;
+                let optimum = solve(&positions.iter().join(","))?;
+                let fuel: i64 = positions.iter().map(|p| fuel_used(*p, target)).sum();
+
+                ensure!(
+                    optimum.as_i128().is_some_and(|o| o <= i128::from(fuel)),
+                    "moving to {} costs only {}, not {}",
+                    target,
+                    fuel,
+                    optimum
+                );
+                Ok(())
+            },
+        );
+    }
+}
@@ -0,0 +1,94 @@
+pub mod part_01;
+pub mod part_02;
+
+use alloc::{collections::BTreeSet, format, vec::Vec};
+use aoc_core::inspect::{numbered_lines, Report};
+
+pub const EXAMPLE_INPUT: &str = include_str!("../resources/day_08/example_input.txt");
+pub const PUZZLE_INPUT: &str = include_str!("../resources/day_08/puzzle_input.txt");
+pub const ANSWERS: &str = include_str!("../resources/day_08/answers.txt");
+
+/// Checks that every line has 10 distinct patterns and 4 outputs, each a
+/// set of the segments `a` to `g`.
+pub fn inspect(input: &str) -> Report {
+    let mut report = Report::new();
+    let mut displays = 0;
+    let mut lengths = [0; 8];
+
+    for (line, text) in numbered_lines(input) {
+        displays += 1;
+
+        let Some((patterns, outputs)) = text.split_once(" | ") else {
+            report.violation(line, "has no ' | ' between the patterns and outputs");
+            continue;
+        };
+        let patterns: Vec<&str> = patterns.split_whitespace().collect();
+        let outputs: Vec<&str> = outputs.split_whitespace().collect();
+
+        if patterns.len() != 10 {
+            report.violation(line, format!("has {} patterns, not 10", patterns.len()));
+        }
+        if outputs.len() != 4 {
+            report.violation(line, format!("has {} outputs, not 4", outputs.len()));
+        }
+
+        for digit in patterns.iter().chain(&outputs) {
+            if let Some(c) = digit.chars().find(|c| !('a'..='g').contains(c)) {
+                report.violation(line, format!("'{}' has the unknown segment '{}'", digit, c));
+            } else if digit.chars().collect::<BTreeSet<_>>().len() != digit.len() {
+                report.violation(line, format!("'{}' repeats a segment", digit));
+            }
+        }
+
+        let distinct = patterns
+            .iter()
+            .map(|p| p.chars().collect::<BTreeSet<_>>())
+            .collect::<BTreeSet<_>>()
+            .len();
+        if distinct != patterns.len() {
+            report.violation(line, "repeats a pattern");
+        }
+
+        for output in &outputs {
+            if let Some(count) = lengths.get_mut(output.len()) {
+                *count += 1;
+            }
+        }
+    }
+
+    report.fact("Displays", displays);
+    report.histogram(
+        "Output segment counts",
+        lengths.into_iter().enumerate().skip(2),
+    );
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inspect_reports_malformed_displays() {
+        let input = "ab cd | ef ga
+acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaz
+acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab cdfeb fcadb cdfeb cdbaf";
+        let report = inspect(input);
+        let messages: Vec<(usize, &str)> = report
+            .violations()
+            .iter()
+            .map(|v| (v.line, v.message.as_str()))
+            .collect();
+
+        assert_eq!(
+            messages,
+            [
+                (1, "has 2 patterns, not 10"),
+                (1, "has 2 outputs, not 4"),
+                (2, "'cdbaz' has the unknown segment 'z'"),
+                (3, "has no ' | ' between the patterns and outputs"),
+            ]
+        );
+    }
+}
@@ -1,15 +1,22 @@
-use super::{EXAMPLE_INPUT, PUZZLE_INPUT};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use anyhow::anyhow;
+use aoc_core::answer::Answer;
+use core::fmt;
+use itertools::Itertools;
 use nom::{
     bytes::complete::tag,
     character::complete::{alpha1, line_ending, space1},
-    combinator::map,
+    combinator::{map, map_res},
     multi::separated_list1,
     sequence::separated_pair,
     IResult,
 };
 
 #[derive(Debug, PartialEq, Eq)]
-struct Digit(String);
+pub(crate) struct Digit(String);
 
 impl From<&str> for Digit {
     fn from(s: &str) -> Self {
@@ -24,7 +31,7 @@ impl Digit {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Display {
+pub(crate) struct Display {
     signal_patterns: [Digit; 10],
     output_value: [Digit; 4],
 }
@@ -35,6 +42,18 @@ impl Display {
     }
 }
 
+/// Prints the display the way it appears in the puzzle input.
+impl fmt::Display for Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} | {}",
+            self.signal_patterns.iter().map(|d| &d.0).join(" "),
+            self.output_value.iter().map(|d| &d.0).join(" ")
+        )
+    }
+}
+
 fn digits(input: &str) -> IResult<&str, Vec<Digit>> {
     map(separated_list1(space1, alpha1), |v: Vec<&str>| {
         v.iter().map(|s| Digit(s.to_string())).collect()
@@ -42,20 +61,14 @@ fn digits(input: &str) -> IResult<&str, Vec<Digit>> {
 }
 
 fn output_value(input: &str) -> IResult<&str, [Digit; 4]> {
-    let (remainder, output) = digits(input)?;
-    let output = output.try_into().expect("if only this could be an error");
-
-    Ok((remainder, output))
+    map_res(digits, <[Digit; 4]>::try_from)(input)
 }
 
 fn signal_patterns(input: &str) -> IResult<&str, [Digit; 10]> {
-    let (remainder, patterns) = digits(input)?;
-    let patterns = patterns.try_into().expect("if only this could be an error");
-
-    Ok((remainder, patterns))
+    map_res(digits, <[Digit; 10]>::try_from)(input)
 }
 
-fn display(input: &str) -> IResult<&str, Display> {
+pub(crate) fn display(input: &str) -> IResult<&str, Display> {
     map(
         separated_pair(signal_patterns, tag(" | "), output_value),
         |(s, o)| Display {
@@ -65,13 +78,36 @@ fn display(input: &str) -> IResult<&str, Display> {
     )(input)
 }
 
-fn parse_input(input: &str) -> IResult<&str, Vec<Display>> {
+pub(crate) fn parse_input(input: &str) -> IResult<&str, Vec<Display>> {
     separated_list1(line_ending, display)(input)
 }
 
+pub fn solve(input: &str) -> anyhow::Result<Answer> {
+    let (_, displays) = parse_input(input).map_err(|e| anyhow!("{}", e))?;
+    let count: usize = displays.iter().map(Display::unique_output_count).sum();
+
+    Ok(u64::try_from(count)?.into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day_08::{EXAMPLE_INPUT, PUZZLE_INPUT};
+    use crate::property::check;
+    use anyhow::ensure;
+
+    /// A non-empty set of segments, from the low seven bits of `mask`.
+    fn segments(mask: u8) -> Digit {
+        let mask = mask % 127 + 1;
+
+        Digit(
+            ('a'..='g')
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, c)| c)
+                .collect(),
+        )
+    }
 
     #[test]
     fn parse_single_line_example() -> anyhow::Result<()> {
@@ -124,4 +160,29 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn printed_display_parses_back() {
+        check(
+            |rng| [(); 14].map(|_| rng.below(256) as u8),
+            |masks| {
+                let mut digits = masks.iter().map(|&m| segments(m));
+                let expected = Display {
+                    signal_patterns: [(); 10].map(|_| digits.next().expect("14 masks")),
+                    output_value: [(); 4].map(|_| digits.next().expect("14 masks")),
+                };
+
+                let printed = expected.to_string();
+                let (remainder, displays) = parse_input(&printed).map_err(|e| anyhow!("{}", e))?;
+
+                ensure!(
+                    displays == [expected],
+                    "parsed something else from {:?}",
+                    printed
+                );
+                ensure!(remainder.is_empty(), "left {:?} unparsed", remainder);
+                Ok(())
+            },
+        );
+    }
 }
@@ -1,16 +1,27 @@
-use super::{EXAMPLE_INPUT, PUZZLE_INPUT};
+use alloc::{
+    collections::BTreeSet,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use anyhow::anyhow;
+use aoc_core::answer::Answer;
+use aoc_core::explain::Explanation;
+use core::fmt;
+use itertools::Itertools;
 use nom::{
     bytes::complete::tag,
     character::complete::{alpha1, line_ending, space1},
-    combinator::map,
+    combinator::{map, map_res},
     multi::separated_list1,
     sequence::separated_pair,
     IResult,
 };
-use std::collections::HashSet;
+use tracing::debug;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct Digit(HashSet<char>);
+struct Digit(BTreeSet<char>);
 
 impl<S: AsRef<str>> From<S> for Digit {
     fn from(s: S) -> Self {
@@ -24,6 +35,13 @@ impl Digit {
     }
 }
 
+impl fmt::Display for Digit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The set keeps the segments in order.
+        write!(f, "{}", self.0.iter().collect::<String>())
+    }
+}
+
 impl PartialEq<u8> for &Digit {
     fn eq(&self, d: &u8) -> bool {
         match self.0.len() {
@@ -36,18 +54,18 @@ impl PartialEq<u8> for &Digit {
     }
 }
 
-impl std::ops::Sub for &Digit {
-    type Output = HashSet<char>;
+impl core::ops::Sub for &Digit {
+    type Output = BTreeSet<char>;
 
     fn sub(self, other: &Digit) -> Self::Output {
         self.0.difference(&other.0).copied().collect()
     }
 }
 
-impl std::ops::Sub<HashSet<char>> for &Digit {
-    type Output = HashSet<char>;
+impl core::ops::Sub<BTreeSet<char>> for &Digit {
+    type Output = BTreeSet<char>;
 
-    fn sub(self, other: HashSet<char>) -> Self::Output {
+    fn sub(self, other: BTreeSet<char>) -> Self::Output {
         self.0.difference(&other).copied().collect()
     }
 }
@@ -143,10 +161,10 @@ struct Solved {
 impl State for Solved {}
 
 #[derive(Debug, PartialEq, Eq)]
-struct Display<State> {
+struct Display<S: State> {
     signal_patterns: [Digit; 10],
     output_value: [Digit; 4],
-    state: State,
+    state: S,
 }
 
 impl<S: State> Display<S> {
@@ -182,6 +200,10 @@ impl Display<Unsolved> {
         let four = four.expect("must have found four");
         let seven = seven.expect("must have found seven");
         let eight = eight.expect("must have found eight");
+        debug!(digit = 1, pattern = %one, "deduced by segment count");
+        debug!(digit = 4, pattern = %four, "deduced by segment count");
+        debug!(digit = 7, pattern = %seven, "deduced by segment count");
+        debug!(digit = 8, pattern = %eight, "deduced by segment count");
 
         Display {
             signal_patterns: self.signal_patterns,
@@ -217,6 +239,7 @@ impl Display<FoundUniques> {
         }
 
         let nine = nine.expect("must have found nine");
+        debug!(digit = 9, pattern = %nine, "deduced");
 
         Display {
             signal_patterns: self.signal_patterns,
@@ -243,6 +266,7 @@ impl Display<FoundNine> {
         }
 
         let two = two.expect("must have found two");
+        debug!(digit = 2, pattern = %two, "deduced");
 
         Display {
             signal_patterns: self.signal_patterns,
@@ -270,6 +294,7 @@ impl Display<FoundTwo> {
         }
 
         let three = three.expect("must have found three");
+        debug!(digit = 3, pattern = %three, "deduced");
 
         Display {
             signal_patterns: self.signal_patterns,
@@ -298,6 +323,7 @@ impl Display<FoundThree> {
         }
 
         let five = five.expect("must have found five");
+        debug!(digit = 5, pattern = %five, "deduced");
 
         Display {
             signal_patterns: self.signal_patterns,
@@ -327,6 +353,7 @@ impl Display<FoundFive> {
         }
 
         let zero = zero.expect("must have found zero");
+        debug!(digit = 0, pattern = %zero, "deduced");
 
         Display {
             signal_patterns: self.signal_patterns,
@@ -357,6 +384,7 @@ impl Display<FoundZero> {
         }
 
         let six = six.expect("must have found six");
+        debug!(digit = 6, pattern = %six, "deduced");
 
         Display {
             signal_patterns: self.signal_patterns,
@@ -377,7 +405,28 @@ impl Display<FoundZero> {
     }
 }
 
+/// What a display's scrambled wiring turned out to be.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Decoding {
+    /// Each digit's signal pattern, with its segments sorted, indexed by digit.
+    pub patterns: [String; 10],
+    pub output_value: u64,
+}
+
 impl Display<Solved> {
+    fn decoding(&self) -> Decoding {
+        let s = &self.state;
+        let digits = [
+            &s.zero, &s.one, &s.two, &s.three, &s.four, &s.five, &s.six, &s.seven, &s.eight,
+            &s.nine,
+        ];
+
+        Decoding {
+            patterns: digits.map(|d| d.to_string()),
+            output_value: self.decode(),
+        }
+    }
+
     fn decode(&self) -> u64 {
         let mut output = String::new();
         for digit in &self.output_value {
@@ -408,24 +457,78 @@ impl Display<Solved> {
     }
 }
 
+impl Display<Solved> {
+    fn explain(&self, explanation: &mut Explanation) {
+        let s = &self.state;
+        let steps = [
+            format!(
+                "`{}` is the only pattern with 2 segments, so it is 1",
+                s.one
+            ),
+            format!(
+                "`{}` is the only pattern with 4 segments, so it is 4",
+                s.four
+            ),
+            format!(
+                "`{}` is the only pattern with 3 segments, so it is 7",
+                s.seven
+            ),
+            format!(
+                "`{}` is the only pattern with 7 segments, so it is 8",
+                s.eight
+            ),
+            format!(
+                "`{}` has 6 segments and only 2 outside of 4 (`{}`), so it is 9",
+                s.nine, s.four
+            ),
+            format!(
+                "`{}` has 5 segments and lights `{}`, the segment 8 has but 9 lacks, so it is 2",
+                s.two,
+                Digit(&s.eight - &s.nine)
+            ),
+            format!(
+                "`{}` has 5 segments and lights both segments of 1 (`{}`), so it is 3",
+                s.three, s.one
+            ),
+            format!(
+                "`{}` is the remaining pattern with 5 segments, so it is 5",
+                s.five
+            ),
+            format!(
+                "`{}` has 6 segments and 2 outside of 5 (`{}`), so it is 0",
+                s.zero, s.five
+            ),
+            format!(
+                "`{}` is the remaining pattern with 6 segments, so it is 6",
+                s.six
+            ),
+        ];
+
+        for step in steps {
+            explanation.step(step);
+        }
+
+        let outputs = self.output_value.iter().map(|d| d.to_string()).join(" ");
+        explanation.step(format!(
+            "The output `{}` decodes to {}",
+            outputs,
+            self.decode()
+        ));
+    }
+}
+
 fn digits(input: &str) -> IResult<&str, Vec<Digit>> {
     map(separated_list1(space1, alpha1), |v: Vec<&str>| {
-        v.iter().map(std::convert::Into::into).collect()
+        v.iter().map(core::convert::Into::into).collect()
     })(input)
 }
 
 fn output_value(input: &str) -> IResult<&str, [Digit; 4]> {
-    let (remainder, output) = digits(input)?;
-    let output = output.try_into().expect("if only this could be an error");
-
-    Ok((remainder, output))
+    map_res(digits, <[Digit; 4]>::try_from)(input)
 }
 
 fn signal_patterns(input: &str) -> IResult<&str, [Digit; 10]> {
-    let (remainder, patterns) = digits(input)?;
-    let patterns = patterns.try_into().expect("if only this could be an error");
-
-    Ok((remainder, patterns))
+    map_res(digits, <[Digit; 10]>::try_from)(input)
 }
 
 fn display(input: &str) -> IResult<&str, Display<Unsolved>> {
@@ -439,9 +542,51 @@ fn parse_input(input: &str) -> IResult<&str, Vec<Display<Unsolved>>> {
     separated_list1(line_ending, display)(input)
 }
 
+pub fn solve(input: &str) -> anyhow::Result<Answer> {
+    let (_, displays) = parse_input(input).map_err(|e| anyhow!("{}", e))?;
+
+    Ok(displays
+        .into_iter()
+        .map(|d| d.solve().decode())
+        .sum::<u64>()
+        .into())
+}
+
+/// Decodes every display's wiring.
+pub fn decodings(input: &str) -> anyhow::Result<Vec<Decoding>> {
+    let (_, displays) = parse_input(input).map_err(|e| anyhow!("{}", e))?;
+
+    Ok(displays.into_iter().map(|d| d.solve().decoding()).collect())
+}
+
+pub fn explain(input: &str) -> anyhow::Result<Explanation> {
+    let (_, displays) = parse_input(input).map_err(|e| anyhow!("{}", e))?;
+    let mut explanation = Explanation::new();
+    let mut values = vec![];
+
+    for (idx, display) in displays.into_iter().enumerate() {
+        let display = display.solve();
+
+        explanation.heading(format!("Entry {}", idx + 1));
+        display.explain(&mut explanation);
+        values.push(display.decode());
+    }
+
+    let total: u64 = values.iter().sum();
+    explanation.heading("Total");
+    explanation.step(format!(
+        "Summing every output value: {} = {}",
+        values.iter().join(" + "),
+        total
+    ));
+
+    Ok(explanation.answer(total))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day_08::{EXAMPLE_INPUT, PUZZLE_INPUT};
 
     #[test]
     fn parse_single_line_example() -> anyhow::Result<()> {
@@ -476,6 +621,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn decodes_single_line_example() -> anyhow::Result<()> {
+        let input =
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+
+        assert_eq!(
+            decodings(input)?,
+            [Decoding {
+                patterns: [
+                    "abcdeg", "ab", "acdfg", "abcdf", "abef", "bcdef", "bcdefg", "abd", "abcdefg",
+                    "abcdef"
+                ]
+                .map(String::from),
+                output_value: 5353,
+            }]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn example() -> anyhow::Result<()> {
         let (_, displays) = parse_input(EXAMPLE_INPUT)?;
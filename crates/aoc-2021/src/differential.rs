@@ -0,0 +1,53 @@
+use crate::{day_06, day_07};
+use aoc_core::{differential::Pair, generate::Size};
+
+pub const PAIRS: &[Pair] = &[
+    Pair {
+        day: 6,
+        name: "fish-vs-school",
+        reference: |input| Ok(day_06::part_01::population_after(input, 80)?.into()),
+        optimised: |input| Ok(day_06::part_02::population_after(input, 80)?.into()),
+        size: Size {
+            count: 30,
+            width: 0,
+        },
+    },
+    Pair {
+        day: 7,
+        name: "scan-vs-median",
+        reference: day_07::part_01::solve_by_scan,
+        optimised: day_07::part_01::solve,
+        size: Size {
+            count: 50,
+            width: 100,
+        },
+    },
+    Pair {
+        day: 7,
+        name: "scan-vs-mean",
+        reference: day_07::part_02::solve,
+        optimised: day_07::part_02::solve_near_mean,
+        size: Size {
+            count: 50,
+            width: 100,
+        },
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::YEAR;
+    use aoc_core::differential::compare;
+
+    #[test]
+    fn every_pair_agrees() -> anyhow::Result<()> {
+        for pair in PAIRS {
+            if let Some(divergence) = compare(YEAR.generator(pair.day)?, pair, 0, 20)? {
+                panic!("day {} {}: {}", pair.day, pair.name, divergence);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{day_01, day_02, day_04, day_06, day_07, day_08};
+use aoc_core::fuzz::Target;
+
+pub const TARGETS: &[Target] = &[
+    Target {
+        name: "day_01::to_depth_iter",
+        seeds: || vec![day_01::EXAMPLE_INPUT],
+        run: |input| day_01::to_depth_iter(input.as_bytes()).all(|depth| depth.is_ok()),
+    },
+    Target {
+        name: "day_02::Command::from_str",
+        seeds: || day_02::EXAMPLE_INPUT.lines().collect(),
+        run: |input| input.parse::<day_02::Command>().is_ok(),
+    },
+    Target {
+        name: "day_04::board",
+        seeds: || {
+            day_04::EXAMPLE_INPUT
+                .split_inclusive("\n\n")
+                .skip(1)
+                .collect()
+        },
+        run: |input| day_04::board(input).is_ok(),
+    },
+    Target {
+        name: "day_04::bingo",
+        seeds: || vec![day_04::EXAMPLE_INPUT],
+        run: |input| day_04::bingo(input).is_ok(),
+    },
+    Target {
+        name: "day_06::parse_timers",
+        seeds: || vec![day_06::EXAMPLE_INPUT],
+        run: |input| day_06::parse_timers(input).is_ok(),
+    },
+    Target {
+        name: "day_07::parse_positions",
+        seeds: || vec![day_07::EXAMPLE_INPUT],
+        run: |input| day_07::parse_positions(input).is_ok(),
+    },
+    Target {
+        name: "day_08::display",
+        seeds: || day_08::EXAMPLE_INPUT.lines().collect(),
+        run: |input| day_08::part_01::display(input).is_ok(),
+    },
+    Target {
+        name: "day_08::parse_input",
+        seeds: || vec![day_08::EXAMPLE_INPUT],
+        run: |input| day_08::part_01::parse_input(input).is_ok(),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{fuzz::fuzz, generate::Rng};
+
+    #[test]
+    fn seeds_are_accepted() {
+        for target in TARGETS {
+            for seed in (target.seeds)() {
+                assert_eq!(target.exec(seed.as_bytes()), Ok(true), "{}", target.name);
+            }
+        }
+    }
+
+    #[test]
+    fn targets_survive_mutation() {
+        for (i, target) in TARGETS.iter().enumerate() {
+            let mut rng = Rng::new(i as u64);
+
+            if let Some(crash) = fuzz(target, vec![], &mut rng, 2000, |input| target.exec(input)) {
+                panic!(
+                    "{} failed on {:?}: {}",
+                    target.name,
+                    String::from_utf8_lossy(&crash.input),
+                    crash.message
+                );
+            }
+        }
+    }
+}
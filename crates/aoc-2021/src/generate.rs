@@ -0,0 +1,223 @@
+use aoc_core::generate::{Generator, Rng, Size};
+use itertools::Itertools;
+
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        default_size: Size {
+            count: 2000,
+            width: 0,
+        },
+        generate: depths,
+    },
+    Generator {
+        day: 2,
+        default_size: Size {
+            count: 1000,
+            width: 0,
+        },
+        generate: commands,
+    },
+    Generator {
+        day: 3,
+        default_size: Size {
+            count: 1000,
+            width: 12,
+        },
+        generate: diagnostics,
+    },
+    Generator {
+        day: 4,
+        default_size: Size {
+            count: 100,
+            width: 0,
+        },
+        generate: bingo,
+    },
+    Generator {
+        day: 6,
+        default_size: Size {
+            count: 300,
+            width: 0,
+        },
+        generate: timers,
+    },
+    Generator {
+        day: 7,
+        default_size: Size {
+            count: 1000,
+            width: 2000,
+        },
+        generate: crabs,
+    },
+    Generator {
+        day: 8,
+        default_size: Size {
+            count: 200,
+            width: 0,
+        },
+        generate: displays,
+    },
+];
+
+/// A sonar sweep that wanders up and down, but never above the surface.
+fn depths(rng: &mut Rng, size: Size) -> String {
+    let mut depth = rng.range(100..=200);
+
+    (0..size.count)
+        .map(|_| {
+            depth = (depth + rng.range(-10..=20)).max(0);
+            depth
+        })
+        .join("\n")
+}
+
+/// Commands that never aim the submarine above the surface, so neither
+/// part's depth can go negative.
+fn commands(rng: &mut Rng, size: Size) -> String {
+    let mut aim = 0;
+
+    (0..size.count)
+        .map(|_| {
+            let amount = rng.range(1..=9);
+
+            match rng.below(3) {
+                0 => format!("forward {}", amount),
+                1 if aim >= amount => {
+                    aim -= amount;
+                    format!("up {}", amount)
+                }
+                _ => {
+                    aim += amount;
+                    format!("down {}", amount)
+                }
+            }
+        })
+        .join("\n")
+}
+
+/// Fixed-width binary lines, adjusted so that no column is tied.
+fn diagnostics(rng: &mut Rng, size: Size) -> String {
+    let width = size.width.max(1);
+    let mut lines: Vec<Vec<u8>> = (0..size.count.max(1))
+        .map(|_| (0..width).map(|_| rng.below(2) as u8).collect())
+        .collect();
+
+    for column in 0..width {
+        let ones = lines.iter().filter(|line| line[column] == 1).count();
+        if ones * 2 == lines.len() {
+            let last = lines.len() - 1;
+            lines[last][column] ^= 1;
+        }
+    }
+
+    lines
+        .iter()
+        .map(|line| line.iter().map(|b| b.to_string()).collect::<String>())
+        .join("\n")
+}
+
+/// Every number from 0 to 99 drawn once, and boards of 25 unique numbers.
+fn bingo(rng: &mut Rng, size: Size) -> String {
+    let mut numbers: Vec<u8> = (0..100).collect();
+
+    rng.shuffle(&mut numbers);
+    let mut output = numbers.iter().join(",");
+    output.push('\n');
+
+    for _ in 0..size.count.max(1) {
+        rng.shuffle(&mut numbers);
+        output.push('\n');
+        for row in numbers[..25].chunks(5) {
+            output.push_str(&row.iter().map(|n| format!("{:>2}", n)).join(" "));
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+fn timers(rng: &mut Rng, size: Size) -> String {
+    (0..size.count.max(1)).map(|_| rng.range(1..=5)).join(",")
+}
+
+fn crabs(rng: &mut Rng, size: Size) -> String {
+    let max = size.width.max(1) as i64;
+
+    (0..size.count.max(1)).map(|_| rng.range(0..=max)).join(",")
+}
+
+/// The segments lit for each digit, on an unscrambled display.
+const SEGMENTS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// Displays whose wires are scrambled by a random permutation, with the
+/// patterns and their segments listed in a random order.
+fn displays(rng: &mut Rng, size: Size) -> String {
+    (0..size.count.max(1))
+        .map(|_| {
+            let mut wires: Vec<char> = ('a'..='g').collect();
+            rng.shuffle(&mut wires);
+            let output_digits: Vec<usize> = (0..4).map(|_| rng.below(10) as usize).collect();
+
+            let mut scrambled = |digit: usize| {
+                let mut pattern: Vec<char> = SEGMENTS[digit]
+                    .bytes()
+                    .map(|s| wires[usize::from(s - b'a')])
+                    .collect();
+                rng.shuffle(&mut pattern);
+                pattern.into_iter().collect::<String>()
+            };
+
+            let mut patterns: Vec<String> = (0..10).map(&mut scrambled).collect();
+            let outputs: Vec<String> = output_digits.into_iter().map(scrambled).collect();
+
+            rng.shuffle(&mut patterns);
+            format!("{} | {}", patterns.join(" "), outputs.join(" "))
+        })
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::find_day;
+
+    #[test]
+    fn every_generated_input_is_solvable() -> anyhow::Result<()> {
+        for generator in GENERATORS {
+            let day = find_day(generator.day)?;
+            let size = Size {
+                count: 50,
+                ..generator.default_size
+            };
+
+            for seed in 0..5 {
+                let input = (generator.generate)(&mut Rng::new(seed), size);
+                for part in day.parts {
+                    // Day 4 part 2 can't pick a loser if the last boards tie.
+                    if (day.number, part.number) == (4, 2) {
+                        continue;
+                    }
+
+                    (part.solve)(&input)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn bingo_boards_have_unique_numbers() {
+        let input = bingo(&mut Rng::new(1), Size { count: 3, width: 0 });
+
+        for board in input.split("\n\n").skip(1) {
+            let numbers: Vec<&str> = board.split_whitespace().collect();
+
+            assert_eq!(numbers.len(), 25);
+            assert_eq!(numbers.iter().unique().count(), 25);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! The 2021 puzzles. Without the default `std` feature, only the solvers,
+//! explanations, visualisations and inspectors are built, on `core` and
+//! `alloc` alone.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+#[cfg(feature = "std")]
+pub mod differential;
+#[cfg(feature = "std")]
+pub mod fuzz;
+#[cfg(feature = "std")]
+pub mod generate;
+#[cfg(test)]
+mod property;
+#[cfg(feature = "std")]
+pub mod solutions;
+
+#[cfg(feature = "std")]
+pub use solutions::YEAR;
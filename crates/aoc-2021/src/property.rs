@@ -0,0 +1,249 @@
+//! A small property-testing harness: values are generated from seeded
+//! `Rng`s, and any counterexample is shrunk before it's reported.
+
+use aoc_core::generate::Rng;
+use std::{
+    fmt::Debug,
+    panic::{self, AssertUnwindSafe},
+};
+
+/// How many generated values each property is checked against.
+const CASES: u64 = 200;
+
+/// An upper bound on shrinking steps, in case a shrink never converges.
+const MAX_SHRINKS: usize = 10_000;
+
+/// A value that can propose smaller versions of itself, simplest first.
+pub(crate) trait Shrink: Clone + Debug {
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! shrink_unsigned {
+    ($($ty:ty),*) => {
+        $(
+            impl Shrink for $ty {
+                fn shrink(&self) -> Vec<Self> {
+                    let mut candidates = vec![0, self / 2, self.saturating_sub(1)];
+                    candidates.dedup();
+                    candidates.retain(|c| c != self);
+                    candidates
+                }
+            }
+        )*
+    };
+}
+
+shrink_unsigned!(u8, u16, u32, u64, usize);
+
+impl Shrink for i64 {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates = vec![0, self / 2, self - self.signum()];
+        if *self < 0 {
+            candidates.insert(1, -self);
+        }
+        candidates.dedup();
+        candidates.retain(|c| c != self);
+        candidates
+    }
+}
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        if *self {
+            vec![false]
+        } else {
+            vec![]
+        }
+    }
+}
+
+impl<T: Shrink> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates = vec![];
+
+        if !self.is_empty() {
+            candidates.push(vec![]);
+        }
+        if self.len() > 1 {
+            let half = self.len() / 2;
+            candidates.push(self[..half].to_vec());
+            candidates.push(self[half..].to_vec());
+        }
+        for i in 0..self.len() {
+            let mut removed = self.clone();
+            removed.remove(i);
+            candidates.push(removed);
+        }
+        for (i, item) in self.iter().enumerate() {
+            for smaller in item.shrink() {
+                let mut replaced = self.clone();
+                replaced[i] = smaller;
+                candidates.push(replaced);
+            }
+        }
+
+        candidates
+    }
+}
+
+/// Arrays keep their length, so only their elements shrink.
+impl<T: Shrink, const N: usize> Shrink for [T; N] {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates = vec![];
+
+        for (i, item) in self.iter().enumerate() {
+            for smaller in item.shrink() {
+                let mut replaced = self.clone();
+                replaced[i] = smaller;
+                candidates.push(replaced);
+            }
+        }
+
+        candidates
+    }
+}
+
+impl<A: Shrink, B: Shrink> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b) = self;
+
+        a.shrink()
+            .into_iter()
+            .map(|a| (a, b.clone()))
+            .chain(b.shrink().into_iter().map(|b| (a.clone(), b)))
+            .collect()
+    }
+}
+
+/// A vector of up to `max_len` values.
+pub(crate) fn vec_of<T>(
+    rng: &mut Rng,
+    max_len: usize,
+    mut generate: impl FnMut(&mut Rng) -> T,
+) -> Vec<T> {
+    let len = rng.below(max_len as u64 + 1) as usize;
+
+    (0..len).map(|_| generate(rng)).collect()
+}
+
+#[derive(Debug)]
+pub(crate) struct Failure<T> {
+    pub seed: u64,
+    pub original: T,
+    pub shrunk: T,
+    pub error: String,
+}
+
+/// Runs a property once, treating a panic as a failure.
+fn falsify<T>(property: &impl Fn(&T) -> anyhow::Result<()>, value: &T) -> Option<String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| property(value))) {
+        Ok(Ok(())) => None,
+        Ok(Err(e)) => Some(format!("{:#}", e)),
+        Err(payload) => Some(aoc_core::runner::panic_message(payload.as_ref()).to_string()),
+    }
+}
+
+/// Searches for a value that breaks the property, then shrinks it greedily:
+/// the first smaller candidate that still fails replaces it, until none do.
+pub(crate) fn find_failure<T: Shrink>(
+    generate: impl Fn(&mut Rng) -> T,
+    property: impl Fn(&T) -> anyhow::Result<()>,
+) -> Option<Failure<T>> {
+    let (seed, original, mut error) = (0..CASES).find_map(|seed| {
+        let value = generate(&mut Rng::new(seed));
+        falsify(&property, &value).map(|error| (seed, value, error))
+    })?;
+
+    let mut shrunk = original.clone();
+    let mut steps = 0;
+    'shrinking: while steps < MAX_SHRINKS {
+        for candidate in shrunk.shrink() {
+            steps += 1;
+            if let Some(e) = falsify(&property, &candidate) {
+                shrunk = candidate;
+                error = e;
+                continue 'shrinking;
+            }
+        }
+        break;
+    }
+
+    Some(Failure {
+        seed,
+        original,
+        shrunk,
+        error,
+    })
+}
+
+/// Checks a property against generated values, panicking with the smallest
+/// counterexample found.
+pub(crate) fn check<T: Shrink>(
+    generate: impl Fn(&mut Rng) -> T,
+    property: impl Fn(&T) -> anyhow::Result<()>,
+) {
+    if let Some(failure) = find_failure(generate, property) {
+        panic!(
+            "property failed for seed {}: {}\n  original: {:?}\n  shrunk:   {:?}",
+            failure.seed, failure.error, failure.original, failure.shrunk
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::ensure;
+
+    #[test]
+    fn integers_shrink_towards_zero() {
+        assert_eq!(10u8.shrink(), vec![0, 5, 9]);
+        assert_eq!(1u8.shrink(), vec![0]);
+        assert_eq!((-4i64).shrink(), vec![0, 4, -2, -3]);
+        assert!(0u64.shrink().is_empty());
+    }
+
+    #[test]
+    fn passing_property_has_no_failure() {
+        let failure = find_failure(
+            |rng| vec_of(rng, 20, |rng| rng.below(10)),
+            |v| {
+                ensure!(v.iter().all(|&n| n < 10));
+                Ok(())
+            },
+        );
+
+        assert!(failure.is_none());
+    }
+
+    #[test]
+    fn failure_is_shrunk_to_the_boundary() {
+        let failure = find_failure(
+            |rng| vec_of(rng, 20, |rng| rng.below(100)),
+            |v| {
+                ensure!(v.iter().sum::<u64>() < 100, "sum is too large");
+                Ok(())
+            },
+        )
+        .expect("some vector must sum to 100 or more");
+
+        assert_eq!(failure.shrunk.iter().sum::<u64>(), 100);
+        assert!(failure.shrunk.len() <= failure.original.len());
+        assert_eq!(failure.error, "sum is too large");
+    }
+
+    #[test]
+    fn panics_count_as_failures() {
+        let failure = find_failure(
+            |rng| rng.below(1000),
+            |&n| {
+                assert!(n < 500, "too big");
+                Ok(())
+            },
+        )
+        .expect("some value must be 500 or more");
+
+        assert_eq!(failure.shrunk, 500);
+        assert_eq!(failure.error, "too big");
+    }
+}
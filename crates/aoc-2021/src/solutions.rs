@@ -0,0 +1,334 @@
+use crate::{
+    day_01, day_02, day_03, day_04, day_06, day_07, day_08, differential::PAIRS, fuzz::TARGETS,
+    generate::GENERATORS,
+};
+use aoc_core::solutions::{Day, Part, Year};
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        example_input: day_01::EXAMPLE_INPUT,
+        puzzle_input: day_01::PUZZLE_INPUT,
+        answers: day_01::ANSWERS,
+        parse: |input| {
+            let depths: Vec<u64> =
+                day_01::to_depth_iter(input.as_bytes()).collect::<anyhow::Result<_>>()?;
+
+            Ok(format!("{:?}", depths))
+        },
+        inspect: day_01::inspect,
+        parts: &[
+            Part {
+                number: 1,
+                solve: day_01::part_01::solve,
+                stream: Some(day_01::part_01::stream),
+                bytes: Some(day_01::part_01::solve_bytes),
+                explain: None,
+                visualise: None,
+            },
+            Part {
+                number: 2,
+                solve: day_01::part_02::solve,
+                stream: Some(day_01::part_02::stream),
+                bytes: Some(day_01::part_02::solve_bytes),
+                explain: None,
+                visualise: None,
+            },
+        ],
+    },
+    Day {
+        number: 2,
+        example_input: day_02::EXAMPLE_INPUT,
+        puzzle_input: day_02::PUZZLE_INPUT,
+        answers: day_02::ANSWERS,
+        parse: |input| {
+            let commands: Vec<day_02::Command> =
+                day_02::to_command_iter(input.as_bytes()).collect::<anyhow::Result<_>>()?;
+
+            Ok(format!("{:#?}", commands))
+        },
+        inspect: day_02::inspect,
+        parts: &[
+            Part {
+                number: 1,
+                solve: day_02::part_01::solve,
+                stream: Some(day_02::part_01::stream),
+                bytes: Some(day_02::part_01::solve_bytes),
+                explain: None,
+                visualise: Some(day_02::part_01::visualise),
+            },
+            Part {
+                number: 2,
+                solve: day_02::part_02::solve,
+                stream: Some(day_02::part_02::stream),
+                bytes: Some(day_02::part_02::solve_bytes),
+                explain: None,
+                visualise: Some(day_02::part_02::visualise),
+            },
+        ],
+    },
+    Day {
+        number: 3,
+        example_input: day_03::EXAMPLE_INPUT,
+        puzzle_input: day_03::PUZZLE_INPUT,
+        answers: day_03::ANSWERS,
+        parse: |input| {
+            let lines: Vec<&str> = input.lines().collect();
+
+            Ok(format!("{:#?}", lines))
+        },
+        inspect: day_03::inspect,
+        parts: &[Part {
+            number: 1,
+            solve: day_03::part_01::solve,
+            stream: None,
+            bytes: None,
+            explain: Some(day_03::part_01::explain),
+            visualise: None,
+        }],
+    },
+    Day {
+        number: 4,
+        example_input: day_04::EXAMPLE_INPUT,
+        puzzle_input: day_04::PUZZLE_INPUT,
+        answers: day_04::ANSWERS,
+        parse: |input| {
+            let (_, bingo) = day_04::bingo(input).map_err(|e| e.to_owned())?;
+
+            Ok(format!("{:#?}", bingo))
+        },
+        inspect: day_04::inspect,
+        parts: &[
+            Part {
+                number: 1,
+                solve: day_04::part_01::solve,
+                stream: None,
+                bytes: None,
+                explain: Some(day_04::part_01::explain),
+                visualise: Some(day_04::part_01::visualise),
+            },
+            Part {
+                number: 2,
+                solve: day_04::part_02::solve,
+                stream: None,
+                bytes: None,
+                explain: Some(day_04::part_02::explain),
+                visualise: Some(day_04::part_02::visualise),
+            },
+        ],
+    },
+    Day {
+        number: 6,
+        example_input: day_06::EXAMPLE_INPUT,
+        puzzle_input: day_06::PUZZLE_INPUT,
+        answers: day_06::ANSWERS,
+        parse: |input| Ok(format!("{:?}", day_06::parse_timers(input)?)),
+        inspect: day_06::inspect,
+        parts: &[
+            Part {
+                number: 1,
+                solve: day_06::part_01::solve,
+                stream: None,
+                bytes: None,
+                explain: None,
+                visualise: None,
+            },
+            Part {
+                number: 2,
+                solve: day_06::part_02::solve,
+                stream: None,
+                bytes: Some(day_06::part_02::solve_bytes),
+                explain: None,
+                visualise: Some(day_06::part_02::visualise),
+            },
+        ],
+    },
+    Day {
+        number: 7,
+        example_input: day_07::EXAMPLE_INPUT,
+        puzzle_input: day_07::PUZZLE_INPUT,
+        answers: day_07::ANSWERS,
+        parse: |input| Ok(format!("{:?}", day_07::parse_positions(input)?)),
+        inspect: day_07::inspect,
+        parts: &[
+            Part {
+                number: 1,
+                solve: day_07::part_01::solve,
+                stream: None,
+                bytes: Some(day_07::part_01::solve_bytes),
+                explain: None,
+                visualise: None,
+            },
+            Part {
+                number: 2,
+                solve: day_07::part_02::solve,
+                stream: None,
+                bytes: None,
+                explain: None,
+                visualise: None,
+            },
+        ],
+    },
+    Day {
+        number: 8,
+        example_input: day_08::EXAMPLE_INPUT,
+        puzzle_input: day_08::PUZZLE_INPUT,
+        answers: day_08::ANSWERS,
+        parse: |input| {
+            let (_, displays) = day_08::part_01::parse_input(input).map_err(|e| e.to_owned())?;
+
+            Ok(format!("{:#?}", displays))
+        },
+        inspect: day_08::inspect,
+        parts: &[
+            Part {
+                number: 1,
+                solve: day_08::part_01::solve,
+                stream: None,
+                bytes: None,
+                explain: None,
+                visualise: None,
+            },
+            Part {
+                number: 2,
+                solve: day_08::part_02::solve,
+                stream: None,
+                bytes: None,
+                explain: Some(day_08::part_02::explain),
+                visualise: None,
+            },
+        ],
+    },
+];
+
+pub const YEAR: Year = Year {
+    number: 2021,
+    days: DAYS,
+    generators: GENERATORS,
+    pairs: PAIRS,
+    fuzz_targets: TARGETS,
+};
+
+pub fn find_day(number: u8) -> anyhow::Result<&'static Day> {
+    YEAR.day(number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{answer::Answer, solutions::InputKind};
+    use test_case::test_case;
+
+    #[test_case(1, 1 => 7)]
+    #[test_case(1, 2 => 5)]
+    #[test_case(2, 1 => 150)]
+    #[test_case(2, 2 => 900)]
+    #[test_case(3, 1 => 198)]
+    #[test_case(4, 1 => 4512)]
+    #[test_case(4, 2 => 1924)]
+    #[test_case(6, 1 => 5934)]
+    #[test_case(6, 2 => 26_984_457_539)]
+    #[test_case(7, 1 => 37)]
+    #[test_case(7, 2 => 168)]
+    #[test_case(8, 1 => 26)]
+    #[test_case(8, 2 => 61229)]
+    fn solves_example(day: u8, part: u8) -> Answer {
+        let day = find_day(day).expect("day must be registered");
+        let part = day.part(part).expect("part must be registered");
+
+        (part.solve)(day.example_input).expect("example must be solvable")
+    }
+
+    #[test_case(3, 1)]
+    #[test_case(4, 1)]
+    #[test_case(4, 2)]
+    #[test_case(8, 2)]
+    fn explanation_agrees_with_solver(day: u8, part: u8) {
+        let day = find_day(day).expect("day must be registered");
+        let part = day.part(part).expect("part must be registered");
+        let explain = part.explain.expect("part must be explainable");
+
+        let answer = (part.solve)(day.example_input).expect("example must be solvable");
+        let md = explain(day.example_input)
+            .expect("example must be explainable")
+            .to_markdown(day, part);
+
+        assert!(md.ends_with(&format!("**Answer: {}**\n", answer)));
+    }
+
+    #[test]
+    fn visualises_every_example() -> anyhow::Result<()> {
+        for (day, part) in YEAR.select(None, None)? {
+            if let Some(visualise) = part.visualise {
+                let frames = visualise(day.example_input)?;
+
+                assert!(!frames.is_empty());
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn every_input_is_valid() {
+        for day in DAYS {
+            for kind in [InputKind::Example, InputKind::Puzzle] {
+                let report = (day.inspect)(day.input(kind));
+
+                assert_eq!(report.violations(), [], "day {} {}", day.number, kind);
+            }
+        }
+    }
+
+    #[test]
+    fn every_known_answer_is_right() -> anyhow::Result<()> {
+        for (day, part) in YEAR.select(None, None)? {
+            for kind in [InputKind::Example, InputKind::Puzzle] {
+                let expected = day.answer(kind, part.number)?;
+                let answer = (part.solve)(day.input(kind))?;
+
+                assert_eq!(
+                    Some(answer),
+                    expected,
+                    "day {} part {} {}",
+                    day.number,
+                    part.number,
+                    kind
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn byte_solvers_agree_with_solvers() -> anyhow::Result<()> {
+        for (day, part) in YEAR.select(None, None)? {
+            if let Some(bytes) = part.bytes {
+                for kind in [InputKind::Example, InputKind::Puzzle] {
+                    let input = day.input(kind);
+
+                    assert_eq!(
+                        bytes(input.as_bytes())?,
+                        (part.solve)(input)?,
+                        "day {} part {} {}",
+                        day.number,
+                        part.number,
+                        kind
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_every_example() -> anyhow::Result<()> {
+        for day in DAYS {
+            (day.parse)(day.example_input)?;
+        }
+
+        Ok(())
+    }
+}
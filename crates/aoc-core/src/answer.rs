@@ -0,0 +1,147 @@
+use alloc::string::{String, ToString};
+use core::{convert::Infallible, fmt, str::FromStr};
+
+/// What a solver answers: usually a number, sometimes text such as letters
+/// read off a grid.
+///
+/// Numbers are kept in the narrowest variant that holds them, and compare
+/// by value whichever variant they're in.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Text(String),
+}
+
+impl Answer {
+    /// The answer as an integer, if it is one.
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            Self::Int(n) => Some(i128::from(*n)),
+            Self::BigInt(n) => Some(*n),
+            Self::Text(_) => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Text(a), Self::Text(b)) => a == b,
+            (a, b) => a.as_i128().is_some() && a.as_i128() == b.as_i128(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl PartialEq<u64> for Answer {
+    fn eq(&self, other: &u64) -> bool {
+        self.as_i128() == Some(i128::from(*other))
+    }
+}
+
+impl PartialEq<Answer> for u64 {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, Self::Text(text) if text == other)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(n) => write!(f, "{}", n),
+            Self::BigInt(n) => write!(f, "{}", n),
+            Self::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+/// Anything that reads as an integer is one; everything else is text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        Ok(match s.parse::<i128>() {
+            Ok(n) => Self::from(n),
+            Err(_) => Self::Text(s.to_string()),
+        })
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        i64::try_from(n).map_or(Self::BigInt(n), Self::Int)
+    }
+}
+
+macro_rules! from_integers {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(n: $int) -> Self {
+                    Self::from(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_integers!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("42" => Answer::Int(42))]
+    #[test_case(" -7\n" => Answer::Int(-7))]
+    #[test_case("18446744073709551615" => Answer::BigInt(u64::MAX.into()))]
+    #[test_case("EFLFJGRF" => Answer::Text("EFLFJGRF".to_string()))]
+    #[test_case("1a" => Answer::Text("1a".to_string()))]
+    fn parses(s: &str) -> Answer {
+        s.parse().unwrap_or_else(|e| match e {})
+    }
+
+    #[test]
+    fn numbers_compare_by_value() {
+        assert_eq!(Answer::BigInt(5), Answer::Int(5));
+        assert_eq!(Answer::from(5_u64), 5);
+        assert_ne!(Answer::from("5"), Answer::from(5));
+        assert_eq!(Answer::from(u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn displays_what_it_parses() {
+        for s in [
+            "0",
+            "-12",
+            "170141183460469231731687303715884105727",
+            "HZKHFEJZ",
+        ] {
+            let answer: Answer = s.parse().unwrap_or_else(|e| match e {});
+
+            assert_eq!(answer.to_string(), s);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Zero-copy parsing of inputs as byte slices, for inputs too large to copy
+//! or validate token by token.
+
+use alloc::{borrow::Cow, string::String};
+
+/// Each non-blank line, trimmed, borrowed from the input.
+pub fn lines(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    input
+        .split(|&b| b == b'\n')
+        .map(<[u8]>::trim_ascii)
+        .filter(|line| !line.is_empty())
+}
+
+/// The fields between each `separator` in a trimmed input, borrowed from it.
+pub fn fields(input: &[u8], separator: u8) -> impl Iterator<Item = &[u8]> {
+    input.trim_ascii().split(move |&b| b == separator)
+}
+
+/// Parses a decimal made only of digits, failing on anything else or on
+/// overflow.
+pub fn parse_u64(digits: &[u8]) -> Option<u64> {
+    if digits.is_empty() {
+        return None;
+    }
+
+    digits.iter().try_fold(0u64, |n, &b| {
+        let digit = b.wrapping_sub(b'0');
+        if digit > 9 {
+            return None;
+        }
+
+        n.checked_mul(10)?.checked_add(u64::from(digit))
+    })
+}
+
+/// Parses a decimal with an optional sign, as [`parse_u64`] does.
+pub fn parse_i64(digits: &[u8]) -> Option<i64> {
+    match digits.split_first() {
+        Some((b'-', digits)) => 0i64.checked_sub_unsigned(parse_u64(digits)?),
+        Some((b'+', digits)) => i64::try_from(parse_u64(digits)?).ok(),
+        _ => i64::try_from(parse_u64(digits)?).ok(),
+    }
+}
+
+/// Bytes as text, for error messages.
+pub fn show(bytes: &[u8]) -> Cow<'_, str> {
+    String::from_utf8_lossy(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use test_case::test_case;
+
+    #[test_case(b"0" => Some(0))]
+    #[test_case(b"199" => Some(199))]
+    #[test_case(b"007" => Some(7))]
+    #[test_case(b"18446744073709551615" => Some(u64::MAX))]
+    #[test_case(b"18446744073709551616" => None; "overflow")]
+    #[test_case(b"" => None; "empty")]
+    #[test_case(b"+1" => None; "sign")]
+    #[test_case(b"1 " => None; "space")]
+    #[test_case(b"1a" => None; "letter")]
+    fn parses_unsigned(digits: &[u8]) -> Option<u64> {
+        parse_u64(digits)
+    }
+
+    #[test_case(b"-9223372036854775808" => Some(i64::MIN))]
+    #[test_case(b"9223372036854775807" => Some(i64::MAX))]
+    #[test_case(b"9223372036854775808" => None; "signed overflow")]
+    #[test_case(b"+16" => Some(16); "plus sign")]
+    #[test_case(b"-0" => Some(0); "negative zero")]
+    #[test_case(b"-" => None; "sign alone")]
+    #[test_case(b"--1" => None; "two signs")]
+    fn parses_signed(digits: &[u8]) -> Option<i64> {
+        parse_i64(digits)
+    }
+
+    #[test]
+    fn parsers_agree_with_the_standard_library() {
+        for n in [0, 1, 9, 10, 99, 1_000_000, i64::MAX, -1, -10, i64::MIN] {
+            let text = alloc::format!("{}", n);
+
+            assert_eq!(parse_i64(text.as_bytes()), text.parse().ok());
+            assert_eq!(parse_u64(text.as_bytes()), text.parse().ok());
+        }
+    }
+
+    #[test]
+    fn lines_skip_blank_lines() {
+        let lines: Vec<_> = lines(b"1\n\n 2 \r\n3").collect();
+
+        assert_eq!(lines, [&b"1"[..], b"2", b"3"]);
+    }
+
+    #[test]
+    fn fields_are_split_untrimmed() {
+        let fields: Vec<_> = fields(b"16,1, 2\n", b',').collect();
+
+        assert_eq!(fields, [&b"16"[..], b"1", b" 2"]);
+    }
+}
@@ -0,0 +1,41 @@
+//! Helpers shared by everything that crosses a C ABI: the solver libraries
+//! and the plugin interface.
+
+use core::{ffi::c_char, ptr};
+
+/// Copies `text` into a C buffer of `capacity` bytes with a NUL terminator,
+/// truncating it to fit. Returns whether it fitted whole.
+///
+/// # Safety
+///
+/// `out_buf` must be valid for writes of `capacity` bytes.
+pub unsafe fn write_c_string(text: &str, out_buf: *mut c_char, capacity: usize) -> bool {
+    if capacity == 0 {
+        return false;
+    }
+
+    let len = text.len().min(capacity - 1);
+    ptr::copy_nonoverlapping(text.as_ptr().cast(), out_buf, len);
+    *out_buf.add(len) = 0;
+
+    len == text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn truncates_to_fit() {
+        let mut buffer = [0x7f as c_char; 4];
+
+        assert!(!unsafe { write_c_string("12345", buffer.as_mut_ptr(), buffer.len()) });
+        assert_eq!(unsafe { CStr::from_ptr(buffer.as_ptr()) }, c"123");
+
+        assert!(unsafe { write_c_string("12", buffer.as_mut_ptr(), buffer.len()) });
+        assert_eq!(unsafe { CStr::from_ptr(buffer.as_ptr()) }, c"12");
+
+        assert!(!unsafe { write_c_string("1", buffer.as_mut_ptr(), 0) });
+    }
+}
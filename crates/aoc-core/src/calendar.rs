@@ -0,0 +1,301 @@
+//! When puzzles unlock: each December, one a day from the 1st to the 25th,
+//! at midnight US Eastern Standard Time (UTC-5).
+
+use alloc::{format, string::String};
+use anyhow::bail;
+
+pub const FIRST_DAY: u8 = 1;
+pub const LAST_DAY: u8 = 25;
+
+/// How far behind UTC the unlock schedule runs.
+const UTC_OFFSET_SECS: i64 = -5 * 60 * 60;
+const SECS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// The current time, as seconds since the Unix epoch, so that tests can
+/// choose what "now" is.
+pub trait Clock {
+    fn now(&self) -> i64;
+}
+
+/// The system's clock.
+#[cfg(feature = "std")]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> i64 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(since) => since.as_secs() as i64,
+            Err(e) => -(e.duration().as_secs() as i64),
+        }
+    }
+}
+
+/// A clock that's stopped at the given time.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedClock(pub i64);
+
+impl Clock for FixedClock {
+    fn now(&self) -> i64 {
+        self.0
+    }
+}
+
+/// Days since the epoch of a proleptic Gregorian date, after Howard Hinnant's
+/// `days_from_civil`.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The date of a day since the epoch, the inverse of [`days_from_civil`].
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+/// When a day's puzzle unlocks, in seconds since the epoch.
+pub fn unlocks_at(year: u16, day: u8) -> anyhow::Result<i64> {
+    if !(FIRST_DAY..=LAST_DAY).contains(&day) {
+        bail!(
+            "day {} doesn't exist; puzzles run from {} to {}",
+            day,
+            FIRST_DAY,
+            LAST_DAY
+        );
+    }
+
+    Ok(days_from_civil(i64::from(year), 12, day) * SECS_PER_DAY - UTC_OFFSET_SECS)
+}
+
+pub fn is_unlocked(clock: &dyn Clock, year: u16, day: u8) -> anyhow::Result<bool> {
+    Ok(clock.now() >= unlocks_at(year, day)?)
+}
+
+/// Fails unless a day's puzzle has unlocked, for anything that would ask the
+/// site about it early.
+pub fn ensure_unlocked(clock: &dyn Clock, year: u16, day: u8) -> anyhow::Result<()> {
+    let at = unlocks_at(year, day)?;
+    let now = clock.now();
+    if now < at {
+        bail!(
+            "day {} of {} is still locked; it unlocks in {}",
+            day,
+            year,
+            format_duration(at - now)
+        );
+    }
+
+    Ok(())
+}
+
+/// The next puzzle to unlock after `now`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Unlock {
+    pub year: u16,
+    pub day: u8,
+    pub at: i64,
+}
+
+pub fn next_unlock(clock: &dyn Clock) -> anyhow::Result<Unlock> {
+    // The schedule's own date decides which day is next.
+    let (year, month, day) =
+        civil_from_days((clock.now() + UTC_OFFSET_SECS).div_euclid(SECS_PER_DAY));
+    let (year, day) = match (month, day) {
+        (12, day) if day >= LAST_DAY => (year + 1, FIRST_DAY),
+        (12, day) => (year, day + 1),
+        _ => (year, FIRST_DAY),
+    };
+    let year = u16::try_from(year)?;
+
+    Ok(Unlock {
+        year,
+        day,
+        at: unlocks_at(year, day)?,
+    })
+}
+
+/// The days of a year's event that have unlocked so far.
+pub fn unlocked_days(clock: &dyn Clock, year: u16) -> anyhow::Result<u8> {
+    let mut unlocked = 0;
+    for day in FIRST_DAY..=LAST_DAY {
+        if is_unlocked(clock, year, day)? {
+            unlocked = day;
+        }
+    }
+
+    Ok(unlocked)
+}
+
+/// A span of seconds as days, hours, minutes and seconds, leaving out any
+/// leading zero units.
+pub fn format_duration(secs: i64) -> String {
+    let (days, rest) = (secs / SECS_PER_DAY, secs % SECS_PER_DAY);
+    let (hours, minutes, secs) = (rest / 3600, rest % 3600 / 60, rest % 60);
+
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{}s", secs),
+        (0, 0, _) => format!("{}m {:02}s", minutes, secs),
+        (0, _, _) => format!("{}h {:02}m {:02}s", hours, minutes, secs),
+        _ => format!("{}d {:02}h {:02}m {:02}s", days, hours, minutes, secs),
+    }
+}
+
+/// A time as `YYYY-MM-DD HH:MM:SS UTC`.
+pub fn format_utc(secs: i64) -> String {
+    let (year, month, day) = civil_from_days(secs.div_euclid(SECS_PER_DAY));
+    let rest = secs.rem_euclid(SECS_PER_DAY);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        rest / 3600,
+        rest % 3600 / 60,
+        rest % 60
+    )
+}
+
+/// Parses a UTC time written as `YYYY-MM-DDTHH:MM:SSZ`.
+pub fn parse_utc(s: &str) -> anyhow::Result<i64> {
+    let invalid = || anyhow::anyhow!("time must look like 2021-12-01T05:00:00Z, not '{}'", s);
+    let fields = s
+        .strip_suffix('Z')
+        .and_then(|s| s.split_once('T'))
+        .and_then(|(date, time)| {
+            let mut date = date.splitn(3, '-').map(str::parse::<i64>);
+            let mut time = time.splitn(3, ':').map(str::parse::<i64>);
+            Some([
+                date.next()?.ok()?,
+                date.next()?.ok()?,
+                date.next()?.ok()?,
+                time.next()?.ok()?,
+                time.next()?.ok()?,
+                time.next()?.ok()?,
+            ])
+        })
+        .ok_or_else(invalid)?;
+    let [year, month, day, hour, minute, second] = fields;
+
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || !(0..24).contains(&hour)
+        || !(0..60).contains(&minute)
+        || !(0..60).contains(&second)
+    {
+        return Err(invalid());
+    }
+
+    let days = days_from_civil(year, month as u8, day as u8);
+    if civil_from_days(days) != (year, month as u8, day as u8) {
+        return Err(invalid());
+    }
+
+    Ok(days * SECS_PER_DAY + hour * 3600 + minute * 60 + second)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn at(s: &str) -> FixedClock {
+        FixedClock(parse_utc(s).expect("time must be valid"))
+    }
+
+    #[test_case(1970, 1, 1 => 0)]
+    #[test_case(2000, 2, 29 => 11_016)]
+    #[test_case(2021, 12, 1 => 18_962)]
+    #[test_case(1969, 12, 31 => -1)]
+    fn converts_dates(year: i64, month: u8, day: u8) -> i64 {
+        let days = days_from_civil(year, month, day);
+        assert_eq!(civil_from_days(days), (year, month, day));
+
+        days
+    }
+
+    #[test]
+    fn unlocks_at_midnight_eastern() -> anyhow::Result<()> {
+        assert_eq!(format_utc(unlocks_at(2021, 1)?), "2021-12-01 05:00:00 UTC");
+        assert_eq!(format_utc(unlocks_at(2021, 25)?), "2021-12-25 05:00:00 UTC");
+        assert!(unlocks_at(2021, 0).is_err());
+        assert!(unlocks_at(2021, 26).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn locks_days_until_their_midnight() -> anyhow::Result<()> {
+        assert!(!is_unlocked(&at("2021-12-01T04:59:59Z"), 2021, 1)?);
+        assert!(is_unlocked(&at("2021-12-01T05:00:00Z"), 2021, 1)?);
+        assert!(!is_unlocked(&at("2021-12-01T05:00:00Z"), 2021, 2)?);
+
+        let err = ensure_unlocked(&at("2021-12-07T04:00:00Z"), 2021, 8).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 8 of 2021 is still locked; it unlocks in 1d 01h 00m 00s"
+        );
+        assert!(ensure_unlocked(&at("2022-01-01T00:00:00Z"), 2021, 25).is_ok());
+
+        Ok(())
+    }
+
+    #[test_case("2021-11-30T12:00:00Z" => (2021, 1); "before the event")]
+    #[test_case("2021-12-01T04:59:59Z" => (2021, 1); "just before the first day")]
+    #[test_case("2021-12-01T05:00:00Z" => (2021, 2); "as the first day unlocks")]
+    #[test_case("2021-12-25T04:59:59Z" => (2021, 25); "just before the final day")]
+    #[test_case("2021-12-25T05:00:00Z" => (2022, 1); "as the final day unlocks")]
+    #[test_case("2021-12-31T23:59:59Z" => (2022, 1); "on new year's eve")]
+    #[test_case("2022-01-01T03:00:00Z" => (2022, 1); "new year in utc but not eastern")]
+    #[test_case("2022-06-01T00:00:00Z" => (2022, 1); "mid year")]
+    fn finds_the_next_unlock(now: &str) -> (u16, u8) {
+        let next = next_unlock(&at(now)).expect("must have a next unlock");
+        assert!(next.at > parse_utc(now).expect("time must be valid"));
+
+        (next.year, next.day)
+    }
+
+    #[test]
+    fn counts_unlocked_days() -> anyhow::Result<()> {
+        assert_eq!(unlocked_days(&at("2021-11-30T00:00:00Z"), 2021)?, 0);
+        assert_eq!(unlocked_days(&at("2021-12-08T05:00:00Z"), 2021)?, 8);
+        assert_eq!(unlocked_days(&at("2022-01-01T00:00:00Z"), 2021)?, 25);
+
+        Ok(())
+    }
+
+    #[test_case(5 => "5s")]
+    #[test_case(65 => "1m 05s")]
+    #[test_case(3600 => "1h 00m 00s")]
+    #[test_case(90_061 => "1d 01h 01m 01s")]
+    fn formats_durations(secs: i64) -> String {
+        format_duration(secs)
+    }
+
+    #[test_case("2021-02-30T00:00:00Z")]
+    #[test_case("2021-12-01 05:00:00")]
+    #[test_case("2021-12-01T24:00:00Z")]
+    #[test_case("2021-12-01T05:00Z")]
+    fn rejects_invalid_times(s: &str) {
+        assert!(parse_utc(s).is_err());
+    }
+}
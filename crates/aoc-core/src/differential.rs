@@ -0,0 +1,155 @@
+use crate::{
+    answer::Answer,
+    generate::{Generator, Rng, Size},
+    runner,
+    solutions::{Solver, Year},
+};
+use anyhow::bail;
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+/// Two implementations of the same answer: a slow one that's easy to trust,
+/// and the optimised one that's actually used.
+pub struct Pair {
+    pub day: u8,
+    pub name: &'static str,
+    pub reference: Solver,
+    pub optimised: Solver,
+    /// The largest input to generate, small enough for the reference.
+    pub size: Size,
+}
+
+pub fn select(year: &Year, day: Option<u8>) -> anyhow::Result<Vec<&'static Pair>> {
+    match day {
+        Some(day) => {
+            let pairs: Vec<_> = year.pairs.iter().filter(|p| p.day == day).collect();
+            if pairs.is_empty() {
+                bail!(
+                    "day {} of {} has no implementations to compare",
+                    day,
+                    year.number
+                );
+            }
+
+            Ok(pairs)
+        }
+        None => Ok(year.pairs.iter().collect()),
+    }
+}
+
+/// The first generated input on which a pair's implementations disagree.
+pub struct Divergence {
+    pub seed: u64,
+    pub input: String,
+    pub reference: String,
+    pub optimised: String,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "diverged on seed {}: reference gave {}, optimised gave {}",
+            self.seed, self.reference, self.optimised
+        )
+    }
+}
+
+/// An answer, or the error in its place, for reports.
+pub fn describe(answer: &anyhow::Result<Answer>) -> String {
+    match answer {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("an error ({})", e),
+    }
+}
+
+/// Runs both implementations on inputs generated from consecutive seeds,
+/// each sized randomly up to the pair's limit. Two errors count as agreement.
+pub fn compare(
+    generator: &Generator,
+    pair: &Pair,
+    first_seed: u64,
+    cases: u64,
+) -> anyhow::Result<Option<Divergence>> {
+    for seed in first_seed..first_seed.saturating_add(cases) {
+        let mut rng = Rng::new(seed);
+        let size = Size {
+            count: 1 + rng.below(pair.size.count as u64) as usize,
+            ..pair.size
+        };
+        let input = (generator.generate)(&mut rng, size);
+
+        let reference = runner::solve(pair.reference, &input);
+        let optimised = runner::solve(pair.optimised, &input);
+        let agree = match (&reference, &optimised) {
+            (Ok(a), Ok(b)) => a == b,
+            (Err(_), Err(_)) => true,
+            _ => false,
+        };
+
+        if !agree {
+            return Ok(Some(Divergence {
+                seed,
+                input,
+                reference: describe(&reference),
+                optimised: describe(&optimised),
+            }));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Saves a divergent input as `day_NN_<name>_seed_<seed>.txt` in `dir`.
+pub fn save(dir: &Path, pair: &Pair, divergence: &Divergence) -> anyhow::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+
+    let path = dir.join(format!(
+        "day_{:02}_{}_seed_{}.txt",
+        pair.day, pair.name, divergence.seed
+    ));
+    fs::write(&path, &divergence.input)?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_and_saves_the_first_divergence() -> anyhow::Result<()> {
+        let pair = Pair {
+            day: 1,
+            name: "capped",
+            reference: |input| Ok(input.lines().count().into()),
+            optimised: |input| Ok(input.lines().count().min(5).into()),
+            size: Size {
+                count: 10,
+                width: 0,
+            },
+        };
+
+        let generator = Generator {
+            day: 1,
+            default_size: pair.size,
+            generate: |rng, size| "1\n".repeat(rng.below(size.count as u64 + 1) as usize),
+        };
+
+        let divergence = compare(&generator, &pair, 0, 100)?.expect("long inputs must diverge");
+        assert!(divergence.input.lines().count() > 5);
+        assert_eq!(divergence.optimised, "5");
+
+        let dir = std::env::temp_dir().join(format!("aoc-2021-diff-{}", std::process::id()));
+        let path = save(&dir, &pair, &divergence)?;
+        let saved = fs::read_to_string(&path)?;
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(saved, divergence.input);
+        assert!(path.ends_with(format!("day_01_capped_seed_{}.txt", divergence.seed)));
+
+        Ok(())
+    }
+}
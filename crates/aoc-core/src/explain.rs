@@ -0,0 +1,135 @@
+use crate::answer::Answer;
+#[cfg(feature = "std")]
+use crate::solutions::{Day, Part};
+use alloc::{string::String, vec::Vec};
+
+// Only rendered with `std`, which the registry types need.
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
+enum Block {
+    Heading(String),
+    Step(String),
+    Table {
+        headers: Vec<String>,
+        rows: Vec<Vec<String>>,
+    },
+}
+
+/// A step-by-step, human-readable account of how an answer was derived.
+#[derive(Default)]
+pub struct Explanation {
+    blocks: Vec<Block>,
+    answer: Option<Answer>,
+}
+
+impl Explanation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn heading(&mut self, text: impl Into<String>) {
+        self.blocks.push(Block::Heading(text.into()));
+    }
+
+    pub fn step(&mut self, text: impl Into<String>) {
+        self.blocks.push(Block::Step(text.into()));
+    }
+
+    pub fn table<H, R>(&mut self, headers: H, rows: impl IntoIterator<Item = R>)
+    where
+        H: IntoIterator,
+        H::Item: Into<String>,
+        R: IntoIterator,
+        R::Item: Into<String>,
+    {
+        self.blocks.push(Block::Table {
+            headers: headers.into_iter().map(Into::into).collect(),
+            rows: rows
+                .into_iter()
+                .map(|row| row.into_iter().map(Into::into).collect())
+                .collect(),
+        });
+    }
+
+    pub fn answer(mut self, answer: impl Into<Answer>) -> Self {
+        self.answer = Some(answer.into());
+        self
+    }
+
+    #[cfg(feature = "std")]
+    pub fn to_markdown(&self, day: &Day, part: &Part) -> String {
+        let mut md = format!("## Day {:02} / Part {}\n", day.number, part.number);
+        // The previous block decides whether a blank line is needed so that
+        // consecutive steps render as a single list.
+        let mut in_list = false;
+
+        for block in &self.blocks {
+            match block {
+                Block::Heading(text) => {
+                    md.push_str(&format!("\n### {}\n", text));
+                    in_list = false;
+                }
+                Block::Step(text) => {
+                    if !in_list {
+                        md.push('\n');
+                    }
+                    md.push_str(&format!("- {}\n", text));
+                    in_list = true;
+                }
+                Block::Table { headers, rows } => {
+                    md.push_str(&format!("\n| {} |\n", headers.join(" | ")));
+                    md.push_str(&format!("|{}\n", " --- |".repeat(headers.len())));
+                    for row in rows {
+                        md.push_str(&format!("| {} |\n", row.join(" | ")));
+                    }
+                    in_list = false;
+                }
+            }
+        }
+
+        if let Some(answer) = &self.answer {
+            md.push_str(&format!("\n**Answer: {}**\n", answer));
+        }
+
+        md
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::tests::YEAR;
+
+    #[test]
+    fn renders_markdown() -> anyhow::Result<()> {
+        let day = YEAR.day(3)?;
+        let part = day.part(1)?;
+
+        let mut explanation = Explanation::new();
+        explanation.heading("Counts");
+        explanation.table(["Column", "Zeros"], [["1", "5"], ["2", "7"]]);
+        explanation.step("first");
+        explanation.step("second");
+
+        let md = explanation.answer(42).to_markdown(day, part);
+
+        assert_eq!(
+            md,
+            "## Day 03 / Part 1
+
+### Counts
+
+| Column | Zeros |
+| --- | --- |
+| 1 | 5 |
+| 2 | 7 |
+
+- first
+- second
+
+**Answer: 42**
+"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+//! Fuzz targets for input parsers, and a mutation loop to drive them
+//! without any instrumentation or nightly toolchain.
+
+use crate::{generate::Rng, runner::panic_message};
+use std::panic::{self, AssertUnwindSafe};
+
+/// Mutated inputs never grow beyond this many bytes.
+pub const MAX_INPUT_LEN: usize = 64 * 1024;
+
+/// Inputs the target accepted are kept for further mutation, up to this many.
+const MAX_CORPUS_LEN: usize = 1024;
+
+/// Bytes and tokens that tend to matter to the parsers.
+const INTERESTING_BYTES: &[u8] = b"0123456789 ,|\n\r\t-+abcdefgz";
+const INTERESTING_TOKENS: &[&str] = &[
+    "0",
+    "8",
+    "9",
+    "255",
+    "256",
+    "-1",
+    "99999999999999999999",
+    " | ",
+    "forward ",
+    "down ",
+    "up ",
+    "\n\n",
+    "\r\n",
+    "abcdefg",
+];
+
+pub struct Target {
+    pub name: &'static str,
+    /// Seed inputs, taken from the day's example.
+    pub seeds: fn() -> Vec<&'static str>,
+    /// Parses the input, returning whether it was accepted.
+    pub run: fn(&str) -> bool,
+}
+
+impl Target {
+    /// Runs the target on raw bytes, reporting a panic as an error.
+    pub fn exec(&self, input: &[u8]) -> Result<bool, String> {
+        let input = String::from_utf8_lossy(input);
+
+        panic::catch_unwind(AssertUnwindSafe(|| (self.run)(&input)))
+            .map_err(|payload| panic_message(payload.as_ref()).to_string())
+    }
+}
+
+fn random_index(rng: &mut Rng, len: usize) -> usize {
+    rng.below(len as u64) as usize
+}
+
+/// Applies a random edit to `input`, sometimes splicing in part of `other`.
+pub fn mutate(rng: &mut Rng, input: &mut Vec<u8>, other: &[u8]) {
+    let at = random_index(rng, input.len() + 1);
+
+    match rng.below(7) {
+        0 if !input.is_empty() => {
+            let i = random_index(rng, input.len());
+            input[i] ^= 1 << rng.below(8);
+        }
+        1 if !input.is_empty() => {
+            let i = random_index(rng, input.len());
+            input[i] = INTERESTING_BYTES[random_index(rng, INTERESTING_BYTES.len())];
+        }
+        2 => input.insert(
+            at,
+            INTERESTING_BYTES[random_index(rng, INTERESTING_BYTES.len())],
+        ),
+        3 if !input.is_empty() => {
+            let len = 1 + random_index(rng, (input.len() - at).clamp(1, 16));
+            input.drain(at..(at + len).min(input.len()));
+        }
+        4 => {
+            let end = at + random_index(rng, input.len() - at + 1);
+            let copy = input[at..end].to_vec();
+            let to = random_index(rng, input.len() + 1);
+            input.splice(to..to, copy);
+        }
+        5 => {
+            let token = INTERESTING_TOKENS[random_index(rng, INTERESTING_TOKENS.len())];
+            input.splice(at..at, token.bytes());
+        }
+        _ => {
+            let from = random_index(rng, other.len() + 1);
+            input.truncate(at);
+            input.extend(&other[from..]);
+        }
+    }
+
+    input.truncate(MAX_INPUT_LEN);
+}
+
+/// An input that made a target fail, and why.
+#[derive(Debug)]
+pub struct Crash {
+    pub input: Vec<u8>,
+    pub message: String,
+}
+
+/// Mutates the target's corpus for `iterations` rounds, passing every input
+/// to `check`, which runs the target and decides whether it misbehaved.
+/// Seeds come first, unmutated, and accepted inputs join the corpus.
+pub fn fuzz(
+    target: &Target,
+    extra_seeds: Vec<Vec<u8>>,
+    rng: &mut Rng,
+    iterations: u64,
+    mut check: impl FnMut(&[u8]) -> Result<bool, String>,
+) -> Option<Crash> {
+    let mut corpus: Vec<Vec<u8>> = (target.seeds)()
+        .into_iter()
+        .map(|s| s.as_bytes().to_vec())
+        .chain(extra_seeds)
+        .collect();
+    if corpus.is_empty() {
+        corpus.push(vec![]);
+    }
+
+    for seed in corpus.clone() {
+        if let Err(message) = check(&seed) {
+            return Some(Crash {
+                input: seed,
+                message,
+            });
+        }
+    }
+
+    for _ in 0..iterations {
+        let mut input = corpus[random_index(rng, corpus.len())].clone();
+        let other = &corpus[random_index(rng, corpus.len())];
+        for _ in 0..=rng.below(4) {
+            mutate(rng, &mut input, other);
+        }
+
+        match check(&input) {
+            Ok(true) if corpus.len() < MAX_CORPUS_LEN => corpus.push(input),
+            Ok(_) => {}
+            Err(message) => return Some(Crash { input, message }),
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutation_respects_the_length_limit() {
+        let mut rng = Rng::new(3);
+        let mut input = vec![b'1'; MAX_INPUT_LEN];
+
+        for _ in 0..100 {
+            mutate(&mut rng, &mut input, b"1,2,3");
+            assert!(input.len() <= MAX_INPUT_LEN);
+        }
+    }
+
+    #[test]
+    fn reports_crashes() {
+        let target = Target {
+            name: "fragile",
+            seeds: || vec!["1,2,3"],
+            run: |input| {
+                assert!(!input.contains("||"), "found a double pipe");
+                true
+            },
+        };
+
+        let crash = fuzz(&target, vec![], &mut Rng::new(0), 100_000, |input| {
+            target.exec(input)
+        })
+        .expect("mutation must eventually produce a double pipe");
+
+        assert!(String::from_utf8_lossy(&crash.input).contains("||"));
+        assert_eq!(crash.message, "found a double pipe");
+    }
+}
@@ -0,0 +1,322 @@
+#[cfg(feature = "std")]
+use {
+    anyhow::Context,
+    flate2::bufread::MultiGzDecoder,
+    memmap2::Mmap,
+    ruzstd::decoding::{FrameDecoder, StreamingDecoder},
+    std::{
+        fs::{self, File},
+        io::{self, BufRead, BufReader, Read},
+        path::Path,
+    },
+};
+
+/// Parses each non-blank line of an input already in memory, as [`records`]
+/// does for a reader.
+pub fn lines<'a, T: 'a>(
+    input: &'a str,
+    parse: fn(&str) -> anyhow::Result<T>,
+) -> impl Iterator<Item = anyhow::Result<T>> + 'a {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(parse)
+}
+
+/// Opens a file for buffered reading, or stdin when the path is `-`. Gzip and
+/// Zstandard files are decompressed as they're read.
+#[cfg(feature = "std")]
+pub fn open(path: &Path) -> anyhow::Result<Box<dyn BufRead>> {
+    let reader: Box<dyn BufRead> = if path == Path::new("-") {
+        Box::new(io::stdin().lock())
+    } else {
+        let file = File::open(path).with_context(|| format!("couldn't open {}", path.display()))?;
+        Box::new(BufReader::new(file))
+    };
+
+    decompress(path, reader).with_context(|| format!("couldn't read {}", path.display()))
+}
+
+/// Maps a file into memory, so that a solver can parse it in place without
+/// reading or copying it. Stdin, pipes, devices and compressed files can't be
+/// mapped, so for them there's no map, and they have to be read instead.
+#[cfg(feature = "std")]
+pub fn map(path: &Path) -> anyhow::Result<Option<Mmap>> {
+    if path == Path::new("-") {
+        return Ok(None);
+    }
+    // Checked before opening, as opening a named pipe waits for a writer, and
+    // closing it again could lose what the writer sends.
+    let metadata =
+        fs::metadata(path).with_context(|| format!("couldn't open {}", path.display()))?;
+    if !metadata.is_file() {
+        return Ok(None);
+    }
+
+    let file = File::open(path).with_context(|| format!("couldn't open {}", path.display()))?;
+    // SAFETY: the map is only ever read, and inputs aren't written to while
+    // they're being solved.
+    let map =
+        unsafe { Mmap::map(&file) }.with_context(|| format!("couldn't map {}", path.display()))?;
+
+    Ok((Compression::detect(path, &map) == Compression::None).then_some(map))
+}
+
+/// Reads a whole input into memory as bytes, for byte solvers given an input
+/// that can't be mapped.
+#[cfg(feature = "std")]
+pub fn read(path: &Path) -> anyhow::Result<Vec<u8>> {
+    let mut input = vec![];
+    open(path)?.read_to_end(&mut input)?;
+
+    Ok(input)
+}
+
+/// How an input is compressed.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compression {
+    None,
+    Gzip,
+    Zstd,
+}
+
+#[cfg(feature = "std")]
+impl Compression {
+    const GZIP_MAGIC: &'static [u8] = &[0x1f, 0x8b];
+    const ZSTD_MAGIC: &'static [u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+    /// Recognises a `.gz` or `.zst` extension, or else the magic bytes at
+    /// the start of the input.
+    pub fn detect(path: &Path, start: &[u8]) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("gz") => Self::Gzip,
+            Some("zst") => Self::Zstd,
+            _ if start.starts_with(Self::GZIP_MAGIC) => Self::Gzip,
+            _ if start.starts_with(Self::ZSTD_MAGIC) => Self::Zstd,
+            _ => Self::None,
+        }
+    }
+}
+
+/// Wraps a reader in whatever decompression its path or first bytes call
+/// for, peeking at those bytes without consuming them.
+#[cfg(feature = "std")]
+pub fn decompress<'a>(
+    path: &Path,
+    mut reader: Box<dyn BufRead + 'a>,
+) -> anyhow::Result<Box<dyn BufRead + 'a>> {
+    Ok(match Compression::detect(path, reader.fill_buf()?) {
+        Compression::None => reader,
+        Compression::Gzip => Box::new(BufReader::new(MultiGzDecoder::new(reader))),
+        Compression::Zstd => Box::new(BufReader::new(ZstdFrames::new(reader)?)),
+    })
+}
+
+/// Decodes every Zstandard frame in a stream, where the decoder alone stops
+/// after the first.
+#[cfg(feature = "std")]
+struct ZstdFrames<R: BufRead> {
+    decoder: Option<StreamingDecoder<R, FrameDecoder>>,
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead> ZstdFrames<R> {
+    fn new(reader: R) -> anyhow::Result<Self> {
+        let decoder = StreamingDecoder::new(reader).context("zstd frame is invalid")?;
+
+        Ok(Self {
+            decoder: Some(decoder),
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead> Read for ZstdFrames<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let Some(decoder) = &mut self.decoder else {
+                return Ok(0);
+            };
+            let read = decoder.read(buf)?;
+            if read > 0 || buf.is_empty() {
+                return Ok(read);
+            }
+
+            // The frame is done, so start on the next, if there is one.
+            if let Some(decoder) = self.decoder.take() {
+                let mut reader = decoder.into_inner();
+                if !reader.fill_buf()?.is_empty() {
+                    let decoder = StreamingDecoder::new(reader)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                    self.decoder = Some(decoder);
+                }
+            }
+        }
+    }
+}
+
+/// Reads a whole input into memory, for solvers that can't stream it.
+#[cfg(feature = "std")]
+pub fn read_to_string(path: &Path) -> anyhow::Result<String> {
+    let mut input = String::new();
+    open(path)?.read_to_string(&mut input)?;
+
+    Ok(input)
+}
+
+/// Parses each non-blank line of a reader, reusing one buffer throughout so
+/// that memory use doesn't grow with the input.
+#[cfg(feature = "std")]
+pub fn records<R: BufRead, T>(
+    mut reader: R,
+    parse: fn(&str) -> anyhow::Result<T>,
+) -> impl Iterator<Item = anyhow::Result<T>> {
+    let mut buffer = String::new();
+
+    std::iter::from_fn(move || loop {
+        buffer.clear();
+        match reader.read_line(&mut buffer) {
+            Ok(0) => return None,
+            Ok(_) => {
+                let line = buffer.trim();
+                if !line.is_empty() {
+                    return Some(parse(line));
+                }
+            }
+            Err(e) => return Some(Err(e.into())),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::{write::GzEncoder, Compression as Level};
+    use ruzstd::encoding::{compress_to_vec, CompressionLevel};
+    use std::io::Write;
+    use test_case::test_case;
+
+    const DEPTHS: &str = "199\n200\n208\n210\n";
+
+    fn gzip(text: &str) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Level::fast());
+        encoder
+            .write_all(text.as_bytes())
+            .expect("writing to a Vec can't fail");
+        encoder.finish().expect("writing to a Vec can't fail")
+    }
+
+    fn zstd(text: &str) -> Vec<u8> {
+        compress_to_vec(text.as_bytes(), CompressionLevel::Fastest)
+    }
+
+    fn depths(path: &str, bytes: Vec<u8>) -> anyhow::Result<Vec<u32>> {
+        let reader = decompress(Path::new(path), Box::new(io::Cursor::new(bytes)))?;
+
+        records(reader, |s| Ok(s.parse()?)).collect()
+    }
+
+    #[test_case("input.txt", &[0x1f, 0x8b, 8] => Compression::Gzip)]
+    #[test_case("input.txt", &[0x28, 0xb5, 0x2f, 0xfd] => Compression::Zstd)]
+    #[test_case("input.gz", b"" => Compression::Gzip)]
+    #[test_case("input.zst", b"199" => Compression::Zstd)]
+    #[test_case("-", b"199" => Compression::None)]
+    fn detects_compression(path: &str, start: &[u8]) -> Compression {
+        Compression::detect(Path::new(path), start)
+    }
+
+    #[test_case("input.gz", gzip(DEPTHS))]
+    #[test_case("input.zst", zstd(DEPTHS))]
+    #[test_case("-", gzip(DEPTHS); "gzip on stdin")]
+    #[test_case("-", zstd(DEPTHS); "zstd on stdin")]
+    #[test_case("-", DEPTHS.into(); "plain text")]
+    fn decompresses_inputs(path: &str, bytes: Vec<u8>) {
+        let depths = depths(path, bytes).expect("input must decompress");
+
+        assert_eq!(depths, [199, 200, 208, 210]);
+    }
+
+    #[test]
+    fn decompresses_every_member_and_frame() -> anyhow::Result<()> {
+        let gzipped = [gzip("1\n2\n"), gzip("3\n")].concat();
+        let zstded = [zstd("1\n2\n"), zstd("3\n")].concat();
+
+        assert_eq!(depths("-", gzipped)?, [1, 2, 3]);
+        assert_eq!(depths("-", zstded)?, [1, 2, 3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_corrupt_inputs() {
+        assert!(depths("input.gz", DEPTHS.into()).is_err());
+        assert!(depths("input.zst", DEPTHS.into()).is_err());
+    }
+
+    #[test]
+    fn maps_only_plain_files() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-input-map-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let plain = dir.join("day_01.txt");
+        let compressed = dir.join("day_01.txt.gz");
+        std::fs::write(&plain, DEPTHS)?;
+        std::fs::write(&compressed, gzip(DEPTHS))?;
+
+        let mapped = map(&plain)?.map(|map| map.to_vec());
+        let unmapped = map(&compressed)?.is_none();
+        let read = read(&compressed)?;
+        std::fs::remove_dir_all(&dir)?;
+
+        assert_eq!(mapped.as_deref(), Some(DEPTHS.as_bytes()));
+        assert!(unmapped);
+        assert!(map(Path::new("-"))?.is_none());
+        assert_eq!(read, DEPTHS.as_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn opens_compressed_files() -> anyhow::Result<()> {
+        let path = std::env::temp_dir().join(format!("aoc-input-{}.gz", std::process::id()));
+        std::fs::write(&path, gzip(DEPTHS))?;
+
+        let input = read_to_string(&path);
+        std::fs::remove_file(&path)?;
+        assert_eq!(input?, DEPTHS);
+
+        Ok(())
+    }
+
+    #[test]
+    fn records_skip_blank_lines() -> anyhow::Result<()> {
+        let input = "1\n\n 2 \r\n3";
+        let values: Vec<u8> =
+            records(input.as_bytes(), |s| Ok(s.parse()?)).collect::<anyhow::Result<_>>()?;
+
+        assert_eq!(values, [1, 2, 3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn lines_match_records() -> anyhow::Result<()> {
+        let input = "1\n\n 2 \r\n3";
+        let values: Vec<u8> = lines(input, |s| Ok(s.parse()?)).collect::<anyhow::Result<_>>()?;
+
+        assert_eq!(values, [1, 2, 3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn records_report_parse_errors() {
+        let input = "1\nx\n3";
+        let values: Vec<bool> = records(input.as_bytes(), |s| Ok(s.parse::<u8>()?))
+            .map(|r| r.is_ok())
+            .collect();
+
+        assert_eq!(values, [true, false, true]);
+    }
+}
@@ -0,0 +1,207 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Display;
+#[cfg(feature = "std")]
+use {
+    crate::solutions::Day,
+    itertools::Itertools,
+    std::io::{self, Write},
+};
+
+/// The widest a histogram bar is drawn, in characters.
+#[cfg(feature = "std")]
+const BAR_WIDTH: usize = 40;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    /// 1-based, like an editor's.
+    pub line: usize,
+    pub message: String,
+}
+
+// Only written out with `std`, which the registry types need.
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
+struct Histogram {
+    title: String,
+    buckets: Vec<(String, u64)>,
+}
+
+/// What an input contains, and where it breaks the puzzle's constraints.
+#[derive(Default)]
+pub struct Report {
+    facts: Vec<(String, String)>,
+    histograms: Vec<Histogram>,
+    violations: Vec<Violation>,
+}
+
+impl Report {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn fact(&mut self, name: impl Into<String>, value: impl Display) {
+        self.facts.push((name.into(), value.to_string()));
+    }
+
+    pub fn histogram<L: Display>(
+        &mut self,
+        title: impl Into<String>,
+        buckets: impl IntoIterator<Item = (L, u64)>,
+    ) {
+        self.histograms.push(Histogram {
+            title: title.into(),
+            buckets: buckets
+                .into_iter()
+                .map(|(label, count)| (label.to_string(), count))
+                .collect(),
+        });
+    }
+
+    pub fn violation(&mut self, line: usize, message: impl Into<String>) {
+        self.violations.push(Violation {
+            line,
+            message: message.into(),
+        });
+    }
+
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    #[cfg(feature = "std")]
+    pub fn write(&self, out: &mut impl Write, day: &Day) -> io::Result<()> {
+        writeln!(out, "Day {:02} input", day.number)?;
+        let name_width = self
+            .facts
+            .iter()
+            .map(|(n, _)| n.chars().count())
+            .max()
+            .unwrap_or(0);
+        for (name, value) in &self.facts {
+            writeln!(out, "  {:<width$}  {}", name, value, width = name_width)?;
+        }
+
+        for histogram in &self.histograms {
+            writeln!(out, "\n{}", histogram.title)?;
+
+            let label_width = histogram
+                .buckets
+                .iter()
+                .map(|(l, _)| l.chars().count())
+                .max()
+                .unwrap_or(0);
+            let max = histogram
+                .buckets
+                .iter()
+                .map(|&(_, c)| c)
+                .max()
+                .unwrap_or(0)
+                .max(1);
+            for (label, count) in &histogram.buckets {
+                let bar = (count * BAR_WIDTH as u64).div_ceil(max) as usize;
+                writeln!(
+                    out,
+                    "  {:>width$} | {} {}",
+                    label,
+                    "█".repeat(bar),
+                    count,
+                    width = label_width
+                )?;
+            }
+        }
+
+        match self.violations.len() {
+            0 => writeln!(out, "\nNo violations"),
+            n => {
+                writeln!(out, "\n{} violation{}", n, if n == 1 { "" } else { "s" })?;
+                for violation in self.violations.iter().sorted_by_key(|v| v.line) {
+                    writeln!(out, "  line {}: {}", violation.line, violation.message)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Numbered, non-blank lines, for inspectors that check an input line by line.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line))
+        .filter(|(_, line)| !line.trim().is_empty())
+}
+
+/// The values of comma-separated lines, each with its line number.
+pub fn comma_separated(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    numbered_lines(input).flat_map(|(line, text)| text.split(',').map(move |v| (line, v.trim())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::tests::YEAR;
+
+    #[test]
+    fn writes_facts_histograms_and_violations() -> anyhow::Result<()> {
+        let mut report = Report::new();
+        report.fact("Values", 3);
+        report.fact("Largest value", 8);
+        report.histogram("Values", [(0, 1), (1, 2)]);
+        report.violation(2, "value 'x' is not a number");
+
+        let mut out = vec![];
+        report.write(&mut out, YEAR.day(6)?)?;
+
+        assert_eq!(
+            String::from_utf8(out)?,
+            "Day 06 input
+  Values         3
+  Largest value  8
+
+Values
+  0 | ████████████████████ 1
+  1 | ████████████████████████████████████████ 2
+
+1 violation
+  line 2: value 'x' is not a number
+"
+        );
+        assert!(!report.is_valid());
+
+        Ok(())
+    }
+
+    #[test]
+    fn aligns_labels_by_characters() -> anyhow::Result<()> {
+        let mut report = Report::new();
+        report.fact("Crabs", 2);
+        report.fact("Médian", 5);
+        report.histogram("Positions", [("0–9", 1), ("10–19", 1)]);
+
+        let mut out = vec![];
+        report.write(&mut out, YEAR.day(6)?)?;
+
+        assert_eq!(
+            String::from_utf8(out)?,
+            "Day 06 input
+  Crabs   2
+  Médian  5
+
+Positions
+    0–9 | ████████████████████████████████████████ 1
+  10–19 | ████████████████████████████████████████ 1
+
+No violations
+"
+        );
+
+        Ok(())
+    }
+}
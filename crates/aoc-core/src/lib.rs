@@ -0,0 +1,31 @@
+//! What every year's solutions share: the registry types, input loading, the
+//! runner, and the engines behind the generate, diff, fuzz and inspect tools.
+//!
+//! Everything that touches the OS is behind the default `std` feature. What's
+//! left, from explanations and reports to frames and generators, needs only
+//! `alloc`, so the solvers built on it can run on embedded and sandboxed
+//! targets too.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod answer;
+pub mod bytes;
+pub mod c_abi;
+pub mod calendar;
+#[cfg(feature = "std")]
+pub mod differential;
+pub mod explain;
+#[cfg(feature = "std")]
+pub mod fuzz;
+pub mod generate;
+pub mod input;
+pub mod inspect;
+#[cfg(feature = "std")]
+pub mod plugin;
+#[cfg(feature = "std")]
+pub mod runner;
+#[cfg(feature = "std")]
+pub mod solutions;
+pub mod visualise;
@@ -0,0 +1,485 @@
+//! Solvers loaded at runtime from shared libraries, so that alternative
+//! solutions can be tried without forking the crate.
+//!
+//! A plugin exports four C functions, which [`export_plugin!`] writes for
+//! plugins written in Rust:
+//!
+//! ```c
+//! uint32_t aoc_plugin_abi_version(void);
+//! size_t aoc_plugin_entry_count(void);
+//! int32_t aoc_plugin_entry(size_t index, AocPluginEntry *entry); // see RawEntry
+//! int32_t aoc_plugin_solve(size_t index, const uint8_t *input, size_t input_len,
+//!                          char *out_buf, size_t *out_len);
+//! ```
+//!
+//! Only `aoc_plugin_abi_version` is guaranteed to keep its signature; the
+//! rest may change whenever [`ABI_VERSION`] does. `aoc_plugin_solve` follows
+//! the same buffer conventions as the solver libraries: on entry `*out_len`
+//! is the buffer's capacity, and on return it's the length of the answer, or
+//! the length needed when the buffer is too small. A failure reports the
+//! length of its reason the same way, so the host can ask again for all of it.
+
+use crate::{answer::Answer, c_abi::write_c_string, runner::panic_message, solutions::Solver};
+use anyhow::{bail, Context};
+use libloading::Library;
+use std::{
+    env::consts::DLL_EXTENSION,
+    ffi::{c_char, CStr},
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    ptr, slice, str,
+    sync::Arc,
+};
+
+/// Bumped whenever the exported functions or their signatures change.
+pub const ABI_VERSION: u32 = 1;
+
+pub const OK: i32 = 0;
+/// The solver failed; the reason is in the output buffer.
+pub const FAILED: i32 = 1;
+pub const BUFFER_TOO_SMALL: i32 = 2;
+pub const NO_SUCH_ENTRY: i32 = 3;
+
+/// An entry as described to the host by `aoc_plugin_entry`.
+#[repr(C)]
+pub struct RawEntry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// NUL-terminated, and valid for as long as the plugin is loaded.
+    pub name: *const c_char,
+}
+
+/// A solver as a Rust plugin declares it to [`export_plugin!`].
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub name: &'static CStr,
+    pub solve: Solver,
+}
+
+/// Exports the given entries as a plugin:
+///
+/// ```ignore
+/// use aoc_core::plugin::Entry;
+///
+/// aoc_core::export_plugin![Entry {
+///     year: 2021,
+///     day: 7,
+///     part: 1,
+///     name: c"crab-aligner",
+///     solve: part_01,
+/// }];
+/// ```
+#[macro_export]
+macro_rules! export_plugin {
+    ($($entry:expr),* $(,)?) => {
+        static AOC_PLUGIN_ENTRIES: &[$crate::plugin::Entry] = &[$($entry),*];
+
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin_abi_version() -> u32 {
+            $crate::plugin::ABI_VERSION
+        }
+
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin_entry_count() -> usize {
+            AOC_PLUGIN_ENTRIES.len()
+        }
+
+        /// # Safety
+        ///
+        /// `entry` must be valid for writes.
+        #[no_mangle]
+        pub unsafe extern "C" fn aoc_plugin_entry(
+            index: usize,
+            entry: *mut $crate::plugin::RawEntry,
+        ) -> i32 {
+            $crate::plugin::describe(AOC_PLUGIN_ENTRIES, index, entry)
+        }
+
+        /// # Safety
+        ///
+        /// See `aoc_core::plugin`.
+        #[no_mangle]
+        pub unsafe extern "C" fn aoc_plugin_solve(
+            index: usize,
+            input: *const u8,
+            input_len: usize,
+            out_buf: *mut ::std::ffi::c_char,
+            out_len: *mut usize,
+        ) -> i32 {
+            $crate::plugin::solve(AOC_PLUGIN_ENTRIES, index, input, input_len, out_buf, out_len)
+        }
+    };
+}
+
+/// Implements `aoc_plugin_entry` for [`export_plugin!`].
+///
+/// # Safety
+///
+/// `out` must be valid for writes.
+#[doc(hidden)]
+pub unsafe fn describe(entries: &[Entry], index: usize, out: *mut RawEntry) -> i32 {
+    let Some(entry) = entries.get(index) else {
+        return NO_SUCH_ENTRY;
+    };
+    if out.is_null() {
+        return FAILED;
+    }
+
+    out.write(RawEntry {
+        year: entry.year,
+        day: entry.day,
+        part: entry.part,
+        name: entry.name.as_ptr(),
+    });
+
+    OK
+}
+
+/// Implements `aoc_plugin_solve` for [`export_plugin!`], making sure that
+/// panics never unwind into the host.
+///
+/// # Safety
+///
+/// `input` must be valid for reads of `input_len` bytes, `out_len` must be
+/// valid for reads and writes, and `out_buf` must be valid for writes of
+/// `*out_len` bytes.
+#[doc(hidden)]
+pub unsafe fn solve(
+    entries: &[Entry],
+    index: usize,
+    input: *const u8,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_len: *mut usize,
+) -> i32 {
+    let Some(entry) = entries.get(index) else {
+        return NO_SUCH_ENTRY;
+    };
+    if input.is_null() || out_buf.is_null() || out_len.is_null() {
+        return FAILED;
+    }
+
+    let capacity = *out_len;
+    let result = match str::from_utf8(slice::from_raw_parts(input, input_len)) {
+        Ok(input) => match panic::catch_unwind(AssertUnwindSafe(|| (entry.solve)(input))) {
+            Ok(answer) => answer.map_err(|e| format!("{:#}", e)),
+            Err(payload) => Err(format!("panicked: {}", panic_message(payload.as_ref()))),
+        },
+        Err(_) => Err("the input is not valid UTF-8".to_string()),
+    };
+
+    match result {
+        Ok(answer) => {
+            let answer = answer.to_string();
+            *out_len = answer.len();
+            if write_c_string(&answer, out_buf, capacity) {
+                OK
+            } else {
+                BUFFER_TOO_SMALL
+            }
+        }
+        Err(reason) => {
+            *out_len = reason.len();
+            write_c_string(&reason, out_buf, capacity);
+            FAILED
+        }
+    }
+}
+
+type AbiVersionFn = unsafe extern "C" fn() -> u32;
+type EntryCountFn = unsafe extern "C" fn() -> usize;
+type EntryFn = unsafe extern "C" fn(usize, *mut RawEntry) -> i32;
+type SolveFn = unsafe extern "C" fn(usize, *const u8, usize, *mut c_char, *mut usize) -> i32;
+
+/// The functions a plugin exports.
+#[derive(Clone, Copy)]
+struct Exports {
+    entry_count: EntryCountFn,
+    entry: EntryFn,
+    solve: SolveFn,
+}
+
+/// A solver from a plugin, which keeps its library loaded for as long as it
+/// lives.
+pub struct PluginSolver {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub name: String,
+    /// The library it was loaded from.
+    pub source: PathBuf,
+    index: usize,
+    call: SolveFn,
+    _library: Option<Arc<Library>>,
+}
+
+impl PluginSolver {
+    pub fn solve(&self, input: &str) -> anyhow::Result<Answer> {
+        let mut buffer = vec![0 as c_char; 16];
+
+        loop {
+            let mut len = buffer.len();
+            let status = unsafe {
+                (self.call)(
+                    self.index,
+                    input.as_ptr(),
+                    input.len(),
+                    buffer.as_mut_ptr(),
+                    &mut len,
+                )
+            };
+            let text = unsafe { CStr::from_ptr(buffer.as_ptr()) }.to_string_lossy();
+
+            match status {
+                OK => return Ok(text.parse().unwrap_or_else(|e| match e {})),
+                BUFFER_TOO_SMALL | FAILED if len >= buffer.len() => buffer.resize(len + 1, 0),
+                FAILED => bail!("{}", text),
+                status => bail!("plugin returned unexpected status {}", status),
+            }
+        }
+    }
+}
+
+fn entries(
+    exports: Exports,
+    source: &Path,
+    library: Option<Arc<Library>>,
+) -> anyhow::Result<Vec<PluginSolver>> {
+    let count = unsafe { (exports.entry_count)() };
+
+    (0..count)
+        .map(|index| {
+            let mut raw = RawEntry {
+                year: 0,
+                day: 0,
+                part: 0,
+                name: ptr::null(),
+            };
+            let status = unsafe { (exports.entry)(index, &mut raw) };
+            if status != OK || raw.name.is_null() {
+                bail!("couldn't describe entry {} (status {})", index, status);
+            }
+            let name = unsafe { CStr::from_ptr(raw.name) }
+                .to_str()
+                .with_context(|| format!("entry {} has a name that isn't UTF-8", index))?;
+
+            Ok(PluginSolver {
+                year: raw.year,
+                day: raw.day,
+                part: raw.part,
+                name: name.to_string(),
+                source: source.to_path_buf(),
+                index,
+                call: exports.solve,
+                _library: library.clone(),
+            })
+        })
+        .collect()
+}
+
+fn check_version(abi_version: AbiVersionFn) -> anyhow::Result<()> {
+    let version = unsafe { abi_version() };
+    if version != ABI_VERSION {
+        bail!(
+            "it uses plugin ABI version {}, but version {} is required",
+            version,
+            ABI_VERSION
+        );
+    }
+
+    Ok(())
+}
+
+/// Loads a plugin's solvers. Plugins are trusted: loading one runs its code.
+pub fn load(path: &Path) -> anyhow::Result<Vec<PluginSolver>> {
+    let load = || -> anyhow::Result<Vec<PluginSolver>> {
+        let library = unsafe { Library::new(path) }?;
+
+        // The version is checked first, as the other signatures depend on it.
+        check_version(unsafe { *library.get(b"aoc_plugin_abi_version\0")? })?;
+
+        let exports = unsafe {
+            Exports {
+                entry_count: *library.get(b"aoc_plugin_entry_count\0")?,
+                entry: *library.get(b"aoc_plugin_entry\0")?,
+                solve: *library.get(b"aoc_plugin_solve\0")?,
+            }
+        };
+
+        entries(exports, path, Some(Arc::new(library)))
+    };
+
+    load().with_context(|| format!("couldn't load plugin {}", path.display()))
+}
+
+/// Loads every plugin in a directory, in name order. A directory that doesn't
+/// exist has no plugins.
+pub fn load_dir(dir: &Path) -> anyhow::Result<Vec<PluginSolver>> {
+    if !dir.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let mut paths = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == DLL_EXTENSION) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut solvers = vec![];
+    for path in paths {
+        solvers.extend(load(&path)?);
+    }
+
+    Ok(solvers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod plugin {
+        use super::{Answer, Entry};
+
+        fn lines(input: &str) -> anyhow::Result<Answer> {
+            Ok(input.lines().count().into())
+        }
+
+        fn fails(_: &str) -> anyhow::Result<Answer> {
+            anyhow::bail!("no answer")
+        }
+
+        fn panics(_: &str) -> anyhow::Result<Answer> {
+            panic!("crab overboard")
+        }
+
+        fn huge(_: &str) -> anyhow::Result<Answer> {
+            Ok(u64::MAX.into())
+        }
+
+        fn letters(_: &str) -> anyhow::Result<Answer> {
+            Ok("EFLFJGRF".into())
+        }
+
+        crate::export_plugin![
+            Entry {
+                year: 2000,
+                day: 1,
+                part: 1,
+                name: c"lines",
+                solve: lines,
+            },
+            Entry {
+                year: 2000,
+                day: 1,
+                part: 2,
+                name: c"fails",
+                solve: fails,
+            },
+            Entry {
+                year: 2000,
+                day: 2,
+                part: 1,
+                name: c"panics",
+                solve: panics,
+            },
+            Entry {
+                year: 2000,
+                day: 2,
+                part: 2,
+                name: c"huge",
+                solve: huge,
+            },
+            Entry {
+                year: 2000,
+                day: 3,
+                part: 1,
+                name: c"letters",
+                solve: letters,
+            },
+        ];
+    }
+
+    const EXPORTS: Exports = Exports {
+        entry_count: plugin::aoc_plugin_entry_count,
+        entry: plugin::aoc_plugin_entry,
+        solve: plugin::aoc_plugin_solve,
+    };
+
+    fn solvers() -> anyhow::Result<Vec<PluginSolver>> {
+        check_version(plugin::aoc_plugin_abi_version)?;
+        entries(EXPORTS, Path::new("test"), None)
+    }
+
+    #[test]
+    fn describes_every_entry() -> anyhow::Result<()> {
+        let described: Vec<_> = solvers()?
+            .into_iter()
+            .map(|s| (s.year, s.day, s.part, s.name))
+            .collect();
+
+        assert_eq!(
+            described,
+            [
+                (2000, 1, 1, "lines".to_string()),
+                (2000, 1, 2, "fails".to_string()),
+                (2000, 2, 1, "panics".to_string()),
+                (2000, 2, 2, "huge".to_string()),
+                (2000, 3, 1, "letters".to_string()),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn solves_through_the_abi() -> anyhow::Result<()> {
+        let solvers = solvers()?;
+
+        assert_eq!(solvers[0].solve("a\nb\nc")?, 3);
+        assert_eq!(solvers[1].solve("").unwrap_err().to_string(), "no answer");
+        assert!(solvers[2]
+            .solve("")
+            .unwrap_err()
+            .to_string()
+            .contains("crab overboard"));
+        // Longer than the initial buffer, so the host has to ask again.
+        assert_eq!(solvers[3].solve("")?, u64::MAX);
+        assert_eq!(solvers[4].solve("")?, "EFLFJGRF");
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_unknown_entries() {
+        let mut len = 8;
+        let mut buffer = [0 as c_char; 8];
+        let status = unsafe {
+            plugin::aoc_plugin_solve(usize::MAX, b"".as_ptr(), 0, buffer.as_mut_ptr(), &mut len)
+        };
+
+        assert_eq!(status, NO_SUCH_ENTRY);
+    }
+
+    #[test]
+    fn rejects_other_abi_versions() {
+        extern "C" fn future_version() -> u32 {
+            ABI_VERSION + 1
+        }
+
+        assert!(check_version(future_version).is_err());
+    }
+
+    #[test]
+    fn missing_directories_have_no_plugins() -> anyhow::Result<()> {
+        assert!(load_dir(Path::new("no/such/plugins"))?.is_empty());
+
+        Ok(())
+    }
+}
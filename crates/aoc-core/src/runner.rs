@@ -0,0 +1,256 @@
+use crate::{
+    answer::Answer,
+    explain::Explanation,
+    input,
+    solutions::{Day, InputKind, Part, Solver},
+};
+use anyhow::anyhow;
+use std::{
+    io::{self, BufRead, Write},
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    str,
+    time::{Duration, Instant},
+};
+
+pub struct Outcome {
+    pub answer: anyhow::Result<Answer>,
+    pub elapsed: Duration,
+}
+
+/// Runs a single part against the given input.
+///
+/// Malformed input is reported through the solver's own errors; panics that
+/// remain in solver logic are caught here as a backstop and reported as
+/// errors instead of taking the caller down.
+pub fn run(part: &Part, input: &str) -> Outcome {
+    let start = Instant::now();
+    let answer = solve(part.solve, input);
+    let elapsed = start.elapsed();
+
+    Outcome { answer, elapsed }
+}
+
+/// Runs a single part against a reader's input, streaming it if the part
+/// can and reading it all into memory otherwise.
+pub fn run_reader(part: &Part, input: &mut dyn BufRead) -> Outcome {
+    let start = Instant::now();
+    let answer = match part.stream {
+        Some(stream) => catch(|| stream(input)),
+        None => {
+            let mut buffer = String::new();
+            match input.read_to_string(&mut buffer) {
+                Ok(_) => solve(part.solve, &buffer),
+                Err(e) => Err(e.into()),
+            }
+        }
+    };
+    let elapsed = start.elapsed();
+
+    Outcome { answer, elapsed }
+}
+
+/// Runs a single part against a file's input, or stdin's when the path is
+/// `-`. Files are mapped rather than read, and handed to the part as bytes if
+/// it can take them. Inputs that can't be mapped are streamed if the part can,
+/// and read into memory otherwise.
+pub fn run_file(part: &Part, path: &Path) -> Outcome {
+    let start = Instant::now();
+    let answer = match input::map(path) {
+        Ok(Some(map)) => match part.bytes {
+            Some(bytes) => catch(|| bytes(&map)),
+            None => match str::from_utf8(&map) {
+                Ok(input) => solve(part.solve, input),
+                Err(e) => Err(anyhow!("{} isn't UTF-8: {}", path.display(), e)),
+            },
+        },
+        Ok(None) => match (part.stream, part.bytes) {
+            (Some(stream), _) => {
+                input::open(path).and_then(|mut input| catch(|| stream(&mut *input)))
+            }
+            (None, Some(bytes)) => input::read(path).and_then(|input| catch(|| bytes(&input))),
+            (None, None) => input::read_to_string(path).and_then(|input| solve(part.solve, &input)),
+        },
+        Err(e) => Err(e),
+    };
+    let elapsed = start.elapsed();
+
+    Outcome { answer, elapsed }
+}
+
+/// Solves `iterations` times, at least once, and reports the median time.
+/// Stops at the first error, or at an answer that differs from the first.
+pub fn bench(iterations: usize, mut solve: impl FnMut() -> anyhow::Result<Answer>) -> Outcome {
+    let mut times = vec![];
+    let mut answer = None;
+
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let this = catch(&mut solve);
+        times.push(start.elapsed());
+
+        let this = match (&answer, this) {
+            (Some(Ok(first)), Ok(this)) if *first != this => {
+                Err(anyhow!("answered {} and then {}", first, this))
+            }
+            (_, this) => this,
+        };
+        let failed = this.is_err();
+        answer = Some(this);
+        if failed {
+            break;
+        }
+    }
+    times.sort_unstable();
+
+    Outcome {
+        answer: answer.expect("solved at least once"),
+        elapsed: times[times.len() / 2],
+    }
+}
+
+/// Calls a solver, turning a panic into an error.
+pub fn solve(solver: Solver, input: &str) -> anyhow::Result<Answer> {
+    catch(|| solver(input))
+}
+
+fn catch<T>(solve: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+    match panic::catch_unwind(AssertUnwindSafe(solve)) {
+        Ok(answer) => answer,
+        Err(payload) => Err(anyhow!("solver panicked: {}", panic_message(&*payload))),
+    }
+}
+
+pub fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s
+    } else {
+        "unknown panic"
+    }
+}
+
+pub fn print_outcome(
+    out: &mut impl Write,
+    day: &Day,
+    part: &Part,
+    outcome: &Outcome,
+) -> io::Result<()> {
+    match &outcome.answer {
+        Ok(answer) => writeln!(
+            out,
+            "Day {:02} / Part {}: {} ({:.2?})",
+            day.number, part.number, answer, outcome.elapsed
+        ),
+        Err(e) => writeln!(
+            out,
+            "Day {:02} / Part {}: error: {:#} ({:.2?})",
+            day.number, part.number, e, outcome.elapsed
+        ),
+    }
+}
+
+/// Prints a Markdown account of how the part's answer was derived. Panics are
+/// caught and reported as errors, as they are when solving.
+pub fn print_explanation(
+    out: &mut impl Write,
+    day: &Day,
+    part: &Part,
+    input: &str,
+) -> anyhow::Result<()> {
+    match part.explain {
+        Some(explain) => {
+            let explanation = catch(|| explain(input))?;
+            write!(out, "{}", explanation.to_markdown(day, part))?;
+        }
+        None => {
+            let mut explanation = Explanation::new();
+            explanation.step("No step-by-step explanation is available for this part");
+            let explanation = explanation.answer(solve(part.solve, input)?);
+            write!(out, "{}", explanation.to_markdown(day, part))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs every part of every given day and prints one line per part.
+pub fn run_and_print<'a>(
+    out: &mut impl Write,
+    days: impl IntoIterator<Item = &'a Day>,
+    kind: InputKind,
+) -> io::Result<()> {
+    for day in days {
+        for part in day.parts {
+            let outcome = run(part, day.input(kind));
+            print_outcome(out, day, part, &outcome)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, process::Command, thread};
+
+    #[test]
+    fn explaining_catches_panics() {
+        let day = Day {
+            number: 1,
+            example_input: "",
+            puzzle_input: "",
+            answers: "",
+            parse: |input| Ok(input.to_string()),
+            inspect: |_| crate::inspect::Report::new(),
+            parts: &[],
+        };
+        let explained = Part {
+            explain: Some(|_| panic!("no explanation")),
+            ..PART
+        };
+        let solved = Part {
+            solve: |_| panic!("no answer"),
+            ..PART
+        };
+
+        for part in [explained, solved] {
+            let error = print_explanation(&mut vec![], &day, &part, "").unwrap_err();
+
+            assert!(error.to_string().starts_with("solver panicked"));
+        }
+    }
+
+    const PART: Part = Part {
+        number: 1,
+        solve: |input| Ok(input.lines().count().into()),
+        stream: None,
+        bytes: Some(|input| Ok(input.split(|&b| b == b'\n').count().into())),
+        explain: None,
+        visualise: None,
+    };
+
+    #[cfg(unix)]
+    #[test]
+    fn runs_inputs_that_cant_be_mapped() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-runner-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        let fifo = dir.join("day_01.txt");
+        let made = Command::new("mkfifo").arg(&fifo).status()?;
+        assert!(made.success());
+
+        let writer = {
+            let fifo = fifo.clone();
+            thread::spawn(move || fs::write(fifo, "199\n200\n208"))
+        };
+        let outcome = run_file(&PART, &fifo);
+        writer.join().expect("the writer doesn't panic")?;
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(outcome.answer?, 3);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,297 @@
+use crate::{
+    answer::Answer, differential::Pair, explain::Explanation, fuzz::Target, generate::Generator,
+    inspect::Report, visualise::Frame,
+};
+use anyhow::{anyhow, bail, Context};
+use std::{fmt, io::BufRead, str::FromStr};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputKind {
+    Example,
+    Puzzle,
+}
+
+impl InputKind {
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::Example => Self::Puzzle,
+            Self::Puzzle => Self::Example,
+        }
+    }
+}
+
+impl fmt::Display for InputKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Example => write!(f, "example"),
+            Self::Puzzle => write!(f, "puzzle"),
+        }
+    }
+}
+
+impl FromStr for InputKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "example" => Ok(Self::Example),
+            "puzzle" => Ok(Self::Puzzle),
+            _ => bail!("input kind must be 'example' or 'puzzle', not '{}'", s),
+        }
+    }
+}
+
+pub type Solver = fn(&str) -> anyhow::Result<Answer>;
+pub type Streamer = fn(&mut dyn BufRead) -> anyhow::Result<Answer>;
+pub type ByteSolver = fn(&[u8]) -> anyhow::Result<Answer>;
+pub type Explainer = fn(&str) -> anyhow::Result<Explanation>;
+pub type Visualiser = fn(&str) -> anyhow::Result<Vec<Frame>>;
+pub type Inspector = fn(&str) -> Report;
+
+pub struct Part {
+    pub number: u8,
+    pub solve: Solver,
+    /// Solves straight from a reader, without holding the input in memory.
+    pub stream: Option<Streamer>,
+    /// Solves straight from the input's bytes, such as a mapped file's,
+    /// without copying them or checking they're UTF-8.
+    pub bytes: Option<ByteSolver>,
+    pub explain: Option<Explainer>,
+    pub visualise: Option<Visualiser>,
+}
+
+pub struct Day {
+    pub number: u8,
+    pub example_input: &'static str,
+    pub puzzle_input: &'static str,
+    /// The known answers, in the format [`parse_answers`] reads.
+    pub answers: &'static str,
+    pub parse: fn(&str) -> anyhow::Result<String>,
+    pub inspect: Inspector,
+    pub parts: &'static [Part],
+}
+
+impl Day {
+    pub fn input(&self, kind: InputKind) -> &'static str {
+        match kind {
+            InputKind::Example => self.example_input,
+            InputKind::Puzzle => self.puzzle_input,
+        }
+    }
+
+    pub fn part(&self, number: u8) -> anyhow::Result<&'static Part> {
+        self.parts
+            .iter()
+            .find(|p| p.number == number)
+            .ok_or_else(|| anyhow!("day {} has no part {}", self.number, number))
+    }
+
+    /// The known answer for a part against an input, if there is one.
+    pub fn answer(&self, kind: InputKind, part: u8) -> anyhow::Result<Option<Answer>> {
+        let answers = parse_answers(self.answers)
+            .with_context(|| format!("day {}'s answers are invalid", self.number))?;
+
+        Ok(answers
+            .into_iter()
+            .find(|&(k, p, _)| (k, p) == (kind, part))
+            .map(|(_, _, answer)| answer))
+    }
+}
+
+/// Parses an answers file, which has a line per answer naming the input and
+/// the part, such as `puzzle 2 1924`. Everything after the part, spaces and
+/// all, is the answer.
+pub fn parse_answers(text: &str) -> anyhow::Result<Vec<(InputKind, u8, Answer)>> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let parse = || -> anyhow::Result<_> {
+                let mut fields = line.trim().splitn(3, ' ');
+                let (Some(kind), Some(part), Some(answer)) =
+                    (fields.next(), fields.next(), fields.next())
+                else {
+                    bail!("expected '<input> <part> <answer>'");
+                };
+                let part = part
+                    .parse()
+                    .map_err(|_| anyhow!("part '{}' is not a number", part))?;
+
+                Ok((
+                    kind.parse()?,
+                    part,
+                    answer.parse().unwrap_or_else(|e| match e {}),
+                ))
+            };
+
+            parse().with_context(|| format!("line {} is invalid", i + 1))
+        })
+        .collect()
+}
+
+/// One year's puzzles, along with the registries the tools draw on.
+pub struct Year {
+    pub number: u16,
+    pub days: &'static [Day],
+    pub generators: &'static [Generator],
+    pub pairs: &'static [Pair],
+    pub fuzz_targets: &'static [Target],
+}
+
+impl Year {
+    pub fn day(&self, number: u8) -> anyhow::Result<&'static Day> {
+        self.days
+            .iter()
+            .find(|d| d.number == number)
+            .ok_or_else(|| anyhow!("day {} of {} has not been solved", number, self.number))
+    }
+
+    /// Resolves an optional day and part into every matching solution.
+    pub fn select(
+        &self,
+        day: Option<u8>,
+        part: Option<u8>,
+    ) -> anyhow::Result<Vec<(&'static Day, &'static Part)>> {
+        match (day, part) {
+            (Some(day), Some(part)) => {
+                let day = self.day(day)?;
+
+                Ok(vec![(day, day.part(part)?)])
+            }
+            (Some(day), None) => {
+                let day = self.day(day)?;
+
+                Ok(day.parts.iter().map(|part| (day, part)).collect())
+            }
+            (None, Some(part)) => Err(anyhow!("part {} requires a day", part)),
+            (None, None) => Ok(self
+                .days
+                .iter()
+                .flat_map(|day| day.parts.iter().map(move |part| (day, part)))
+                .collect()),
+        }
+    }
+
+    pub fn generator(&self, day: u8) -> anyhow::Result<&'static Generator> {
+        self.generators
+            .iter()
+            .find(|g| g.day == day)
+            .ok_or_else(|| anyhow!("day {} of {} has no input generator", day, self.number))
+    }
+
+    pub fn fuzz_target(&self, name: &str) -> anyhow::Result<&'static Target> {
+        self.fuzz_targets
+            .iter()
+            .find(|t| t.name == name)
+            .ok_or_else(|| anyhow!("{} has no fuzz target called '{}'", self.number, name))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    const PARTS: &[Part] = &[
+        Part {
+            number: 1,
+            solve: |input| Ok(input.lines().count().into()),
+            stream: None,
+            bytes: None,
+            explain: None,
+            visualise: None,
+        },
+        Part {
+            number: 2,
+            solve: |input| Ok(input.len().into()),
+            stream: None,
+            bytes: None,
+            explain: None,
+            visualise: None,
+        },
+    ];
+
+    /// A stand-in for a real year's registry.
+    pub(crate) const YEAR: Year = Year {
+        number: 2000,
+        days: &[
+            Day {
+                number: 3,
+                example_input: "1\n2\n",
+                puzzle_input: "1\n2\n3\n",
+                answers: "example 1 2\npuzzle 2 6\n",
+                parse: |input| Ok(input.to_string()),
+                inspect: |_| Report::new(),
+                parts: PARTS,
+            },
+            Day {
+                number: 6,
+                example_input: "1,2",
+                puzzle_input: "1,2,3",
+                answers: "",
+                parse: |input| Ok(input.to_string()),
+                inspect: |_| Report::new(),
+                parts: PARTS.split_at(1).0,
+            },
+        ],
+        generators: &[],
+        pairs: &[],
+        fuzz_targets: &[],
+    };
+
+    #[test]
+    fn finds_days_and_parts() -> anyhow::Result<()> {
+        let day = YEAR.day(3)?;
+
+        assert_eq!(day.part(2)?.number, 2);
+        assert!(day.part(3).is_err());
+        assert!(YEAR.day(4).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn selects_parts() -> anyhow::Result<()> {
+        let numbers = |selected: Vec<(&Day, &Part)>| -> Vec<(u8, u8)> {
+            selected
+                .into_iter()
+                .map(|(day, part)| (day.number, part.number))
+                .collect()
+        };
+
+        assert_eq!(numbers(YEAR.select(None, None)?), [(3, 1), (3, 2), (6, 1)]);
+        assert_eq!(numbers(YEAR.select(Some(3), None)?), [(3, 1), (3, 2)]);
+        assert_eq!(numbers(YEAR.select(Some(3), Some(2))?), [(3, 2)]);
+        assert!(YEAR.select(None, Some(1)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn finds_known_answers() -> anyhow::Result<()> {
+        let day = YEAR.day(3)?;
+
+        assert_eq!(day.answer(InputKind::Example, 1)?, Some(Answer::Int(2)));
+        assert_eq!(day.answer(InputKind::Puzzle, 2)?, Some(Answer::Int(6)));
+        assert_eq!(day.answer(InputKind::Puzzle, 1)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_text_answers_and_rejects_bad_lines() -> anyhow::Result<()> {
+        let answers = parse_answers("\npuzzle 1 AB CD\nexample 2 -3\n")?;
+
+        assert_eq!(
+            answers,
+            [
+                (InputKind::Puzzle, 1, Answer::from("AB CD")),
+                (InputKind::Example, 2, Answer::Int(-3)),
+            ]
+        );
+        assert!(parse_answers("puzzle 1").is_err());
+        assert!(parse_answers("sample 1 2").is_err());
+        assert!(parse_answers("puzzle one 2").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+use alloc::{string::String, vec, vec::Vec};
+#[cfg(feature = "std")]
+use {
+    crossterm::{
+        cursor, queue,
+        style::{Color, Print, ResetColor, SetForegroundColor},
+        terminal::{self, ClearType},
+    },
+    std::{
+        io::{self, Write},
+        thread,
+        time::Duration,
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Colour {
+    Plain,
+    Dim,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Cyan,
+}
+
+#[cfg(feature = "std")]
+impl From<Colour> for Color {
+    fn from(colour: Colour) -> Self {
+        match colour {
+            Colour::Plain => Color::Reset,
+            Colour::Dim => Color::DarkGrey,
+            Colour::Red => Color::Red,
+            Colour::Green => Color::Green,
+            Colour::Yellow => Color::Yellow,
+            Colour::Blue => Color::Blue,
+            Colour::Cyan => Color::Cyan,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pixel {
+    pub ch: char,
+    pub colour: Colour,
+}
+
+impl Pixel {
+    const BLANK: Self = Self {
+        ch: ' ',
+        colour: Colour::Plain,
+    };
+}
+
+/// A grid of coloured characters, plus a caption describing it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    pixels: Vec<Pixel>,
+    pub caption: String,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![Pixel::BLANK; width * height],
+            caption: String::new(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Pixel {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Sets a single pixel, ignoring anything outside of the frame.
+    pub fn set(&mut self, x: usize, y: usize, ch: char, colour: Colour) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = Pixel { ch, colour };
+        }
+    }
+
+    pub fn text(&mut self, x: usize, y: usize, s: &str, colour: Colour) {
+        for (i, ch) in s.chars().enumerate() {
+            self.set(x + i, y, ch, colour);
+        }
+    }
+
+    /// Copies another frame's pixels into this one, with its top left at (x, y).
+    pub fn blit(&mut self, x: usize, y: usize, other: &Frame) {
+        for oy in 0..other.height {
+            for ox in 0..other.width {
+                let Pixel { ch, colour } = other.get(ox, oy);
+                self.set(x + ox, y + oy, ch, colour);
+            }
+        }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    #[cfg(feature = "std")]
+    fn rows(&self) -> impl Iterator<Item = &[Pixel]> {
+        self.pixels.chunks(self.width.max(1))
+    }
+}
+
+/// Something that can draw its current state as a frame.
+pub trait Visualise {
+    fn frame(&self) -> Frame;
+}
+
+/// Lays frames out left to right, wrapping to keep each row within `width`.
+pub fn tile(frames: &[Frame], width: usize, gap: usize) -> Frame {
+    let tile_width = frames.iter().map(Frame::width).max().unwrap_or(0);
+    let tile_height = frames.iter().map(Frame::height).max().unwrap_or(0);
+    let per_row = ((width + gap) / (tile_width + gap)).max(1);
+    let rows = frames.len().div_ceil(per_row);
+    let cols = per_row.min(frames.len());
+
+    let mut tiled = Frame::new(
+        (cols * (tile_width + gap)).saturating_sub(gap),
+        (rows * (tile_height + gap)).saturating_sub(gap),
+    );
+    for (i, frame) in frames.iter().enumerate() {
+        let (row, col) = (i / per_row, i % per_row);
+        tiled.blit(col * (tile_width + gap), row * (tile_height + gap), frame);
+    }
+
+    tiled
+}
+
+/// Draws a frame at the top left of the terminal, cropped to fit.
+#[cfg(feature = "std")]
+pub fn draw(out: &mut impl Write, frame: &Frame) -> io::Result<()> {
+    let (cols, lines) = terminal::size().unwrap_or((80, 24));
+    let (cols, lines) = (usize::from(cols), usize::from(lines));
+
+    queue!(out, cursor::MoveTo(0, 0), terminal::Clear(ClearType::All))?;
+    for (y, row) in frame.rows().take(lines.saturating_sub(1)).enumerate() {
+        queue!(out, cursor::MoveTo(0, y as u16))?;
+
+        let mut current = None;
+        for pixel in row.iter().take(cols) {
+            if current != Some(pixel.colour) {
+                queue!(out, SetForegroundColor(pixel.colour.into()))?;
+                current = Some(pixel.colour);
+            }
+            queue!(out, Print(pixel.ch))?;
+        }
+    }
+
+    let caption_row = frame.height.min(lines.saturating_sub(1));
+    queue!(
+        out,
+        ResetColor,
+        cursor::MoveTo(0, caption_row as u16),
+        Print(frame.caption.chars().take(cols).collect::<String>()),
+    )?;
+
+    out.flush()
+}
+
+/// Animates frames at `fps`, stopping early once `stop` returns true.
+#[cfg(feature = "std")]
+pub fn play(
+    out: &mut impl Write,
+    frames: &[Frame],
+    fps: u32,
+    mut stop: impl FnMut() -> io::Result<bool>,
+) -> io::Result<()> {
+    let delay = Duration::from_secs(1) / fps.max(1);
+
+    queue!(out, cursor::Hide)?;
+    for frame in frames {
+        draw(out, frame)?;
+        thread::sleep(delay);
+
+        if stop()? {
+            break;
+        }
+    }
+    queue!(out, cursor::Show, Print("\r\n"))?;
+
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_ignores_out_of_bounds() {
+        let mut frame = Frame::new(2, 2);
+        frame.set(2, 0, 'x', Colour::Red);
+        frame.set(0, 2, 'x', Colour::Red);
+
+        assert_eq!(frame, Frame::new(2, 2));
+    }
+
+    #[test]
+    fn tile_wraps_to_width() {
+        let mut a = Frame::new(2, 1);
+        a.text(0, 0, "aa", Colour::Plain);
+        let mut b = Frame::new(2, 1);
+        b.text(0, 0, "bb", Colour::Plain);
+
+        let tiled = tile(&[a.clone(), b.clone(), a], 5, 1);
+
+        assert_eq!((tiled.width(), tiled.height()), (5, 3));
+        assert_eq!(tiled.get(3, 0).ch, 'b');
+        assert_eq!(tiled.get(0, 2).ch, 'a');
+        assert_eq!(tiled.get(0, 1).ch, ' ');
+    }
+}
@@ -0,0 +1,245 @@
+//! Benchmarks parsing and solving large generated inputs, read into memory
+//! the way the days used to take them, and mapped and parsed in place.
+
+use anyhow::{bail, Context};
+use aoc::{
+    allocations::{self, Counting},
+    table::{self, Format, Table},
+};
+use aoc_2021::YEAR;
+use aoc_core::{
+    bytes,
+    generate::{Rng, Size},
+    input,
+    runner::{self, Outcome},
+};
+use clap::Parser;
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+#[derive(Parser)]
+#[command(about = "Benchmark parsing and solving large Advent of Code inputs")]
+struct Cli {
+    /// Only benchmark this day
+    #[arg(long)]
+    day: Option<u8>,
+    /// Lines or values in each generated input
+    #[arg(long, default_value_t = 100_000_000)]
+    count: usize,
+    /// How many times to run each, reporting the median time
+    #[arg(long, default_value_t = 3)]
+    iterations: usize,
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Where to write the generated inputs
+    #[arg(long, value_name = "DIR", default_value = "target/bench")]
+    dir: PathBuf,
+    /// Print aligned `table`s or `markdown`
+    #[arg(long, default_value_t)]
+    format: Format,
+}
+
+/// A day's values parsed with `str::parse` from a `String`, as the days used
+/// to, and with the byte parsers in place. Both add the values up, so that
+/// neither can be optimised away.
+struct Parsers {
+    day: u8,
+    text: fn(&str) -> Option<i64>,
+    bytes: fn(&[u8]) -> Option<i64>,
+}
+
+const PARSERS: &[Parsers] = &[
+    Parsers {
+        day: 1,
+        text: |text| {
+            text.lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .try_fold(0, |sum, line| Some(sum + line.parse::<i64>().ok()?))
+        },
+        bytes: |input| {
+            bytes::lines(input).try_fold(0, |sum, line| Some(sum + bytes::parse_i64(line)?))
+        },
+    },
+    Parsers {
+        day: 2,
+        text: |text| {
+            text.lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .try_fold(0, |sum, line| {
+                    let (_, amount) = line.split_once(' ')?;
+                    Some(sum + amount.parse::<i64>().ok()?)
+                })
+        },
+        bytes: |input| {
+            bytes::lines(input).try_fold(0, |sum, line| {
+                let space = line.iter().position(|&b| b == b' ')?;
+                Some(sum + bytes::parse_i64(&line[space + 1..])?)
+            })
+        },
+    },
+    Parsers {
+        day: 6,
+        text: |text| {
+            text.trim()
+                .split(',')
+                .try_fold(0, |sum, timer| Some(sum + timer.parse::<i64>().ok()?))
+        },
+        bytes: |input| {
+            bytes::fields(input, b',')
+                .try_fold(0, |sum, timer| Some(sum + bytes::parse_i64(timer)?))
+        },
+    },
+    Parsers {
+        day: 7,
+        text: |text| {
+            text.trim()
+                .split(',')
+                .try_fold(0, |sum, position| Some(sum + position.parse::<i64>().ok()?))
+        },
+        bytes: |input| {
+            bytes::fields(input, b',')
+                .try_fold(0, |sum, position| Some(sum + bytes::parse_i64(position)?))
+        },
+    },
+];
+
+/// Generates a day's input with `count` lines or values, and saves it.
+fn generate(cli: &Cli, day: u8) -> anyhow::Result<PathBuf> {
+    let generator = YEAR.generator(day)?;
+    let size = Size {
+        count: cli.count,
+        width: generator.default_size.width,
+    };
+    let input = (generator.generate)(&mut Rng::new(cli.seed), size);
+
+    fs::create_dir_all(&cli.dir)?;
+    let path = cli.dir.join(format!("day_{:02}.txt", day));
+    fs::write(&path, input).with_context(|| format!("failed to write {}", path.display()))?;
+
+    Ok(path)
+}
+
+/// Times `run`, failing on an error rather than timing it.
+fn time<T>(
+    iterations: usize,
+    mut run: impl FnMut() -> anyhow::Result<T>,
+) -> anyhow::Result<(T, Duration)> {
+    let mut result = None;
+    let Outcome { answer, elapsed } = runner::bench(iterations, || {
+        result = Some(run()?);
+        Ok(0u64.into())
+    });
+    answer?;
+
+    Ok((result.expect("ran at least once"), elapsed))
+}
+
+fn megabytes(bytes: u64) -> String {
+    format!("{:.1} MB", bytes as f64 / 1e6)
+}
+
+fn speed_up(before: Duration, after: Duration) -> String {
+    format!("{:.1}x", before.as_secs_f64() / after.as_secs_f64())
+}
+
+fn parse_row(cli: &Cli, parsers: &Parsers, path: &Path) -> anyhow::Result<[String; 5]> {
+    let (text_sum, text_time) = time(cli.iterations, || {
+        let text = input::read_to_string(path)?;
+        (parsers.text)(&text).context("str::parse failed")
+    })?;
+    let (bytes_sum, bytes_time) = time(cli.iterations, || {
+        let map = input::map(path)?.context("input must be mappable")?;
+        (parsers.bytes)(&map).context("byte parser failed")
+    })?;
+    if text_sum != bytes_sum {
+        bail!(
+            "day {}'s parsers disagree: {} and {}",
+            parsers.day,
+            text_sum,
+            bytes_sum
+        );
+    }
+
+    Ok([
+        format!("Day {:02}", parsers.day),
+        megabytes(fs::metadata(path)?.len()),
+        format!("{:.2?}", text_time),
+        format!("{:.2?}", bytes_time),
+        speed_up(text_time, bytes_time),
+    ])
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let mut stdout = io::stdout();
+
+    let mut parsing = Table::new(["Day", "Input", "str::parse", "Bytes", "Speed-up"]);
+    let mut solving = Table::new([
+        "Day / Part",
+        "Read and solve",
+        "Allocated",
+        "Map and solve",
+        "Allocated",
+        "Speed-up",
+    ]);
+    let days = match cli.day {
+        Some(day) => vec![YEAR.day(day)?],
+        None => YEAR.days.iter().collect(),
+    };
+    for day in days {
+        let Some(parsers) = PARSERS.iter().find(|p| p.day == day.number) else {
+            continue;
+        };
+        eprintln!("Generating day {} with {} values...", day.number, cli.count);
+        let path = generate(&cli, day.number)?;
+
+        parsing.push(parse_row(&cli, parsers, &path)?);
+        for part in day.parts.iter().filter(|part| part.bytes.is_some()) {
+            let (read, read_memory) = allocations::peak(|| {
+                time(cli.iterations, || {
+                    let text = input::read_to_string(path.as_path())?;
+                    (part.solve)(&text)
+                })
+            });
+            let (mapped, mapped_memory) =
+                allocations::peak(|| time(cli.iterations, || runner::run_file(part, &path).answer));
+            let ((read, read_time), (mapped, mapped_time)) = (read?, mapped?);
+            if read != mapped {
+                bail!(
+                    "day {} part {} answered {} when read and {} when mapped",
+                    day.number,
+                    part.number,
+                    read,
+                    mapped
+                );
+            }
+
+            solving.push([
+                format!("Day {:02} / Part {}", day.number, part.number),
+                format!("{:.2?}", read_time),
+                megabytes(read_memory as u64),
+                format!("{:.2?}", mapped_time),
+                megabytes(mapped_memory as u64),
+                speed_up(read_time, mapped_time),
+            ]);
+        }
+        fs::remove_file(&path)?;
+    }
+
+    table::write_heading(&mut stdout, cli.format, "Parsing")?;
+    parsing.write(&mut stdout, cli.format)?;
+    writeln!(stdout)?;
+    table::write_heading(&mut stdout, cli.format, "Solving")?;
+    solving.write(&mut stdout, cli.format)?;
+
+    Ok(())
+}
@@ -0,0 +1,218 @@
+//! Runs the parser fuzz targets, failing on panics, runaway allocation and
+//! inputs that take too long to parse.
+
+use anyhow::bail;
+use aoc::{
+    allocations::{self, Counting},
+    years::find_year,
+};
+use aoc_core::{
+    fuzz::{self, Crash, Target},
+    generate::Rng,
+    solutions::Year,
+};
+use clap::Parser;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// How much a target may allocate while parsing an input of `len` bytes.
+fn allocation_limit(len: usize) -> usize {
+    1024 * 1024 + 256 * len
+}
+
+#[derive(Parser)]
+#[command(about = "Fuzz the Advent of Code input parsers")]
+struct Cli {
+    /// Which year's parsers to fuzz, defaulting to the latest
+    #[arg(long)]
+    year: Option<u16>,
+    /// Only fuzz this target, e.g. `day_04::bingo`
+    #[arg(long)]
+    target: Option<String>,
+    /// Mutated inputs to try per target
+    #[arg(long, default_value_t = 100_000)]
+    iterations: u64,
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// How long a single input may take before it counts as a hang
+    #[arg(long, value_name = "MS", default_value_t = 1000)]
+    timeout: u64,
+    /// Extra seed inputs, read from a subdirectory per year and target
+    #[arg(long, value_name = "DIR", default_value = "fuzz/corpus")]
+    corpus: PathBuf,
+    /// Where to save inputs that crash a target
+    #[arg(long, value_name = "DIR", default_value = "fuzz/crashes")]
+    crashes: PathBuf,
+    /// List the targets and exit
+    #[arg(long)]
+    list: bool,
+}
+
+fn directory(root: &Path, year: &Year, target: &Target) -> PathBuf {
+    root.join(year.number.to_string())
+        .join(target.name.replace("::", "_"))
+}
+
+fn read_corpus(dir: &Path) -> anyhow::Result<Vec<Vec<u8>>> {
+    if !dir.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let mut inputs = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() {
+            inputs.push(fs::read(path)?);
+        }
+    }
+
+    Ok(inputs)
+}
+
+/// Saves a crashing input under a name derived from its FNV-1a hash.
+fn save_crash(dir: &Path, input: &[u8]) -> anyhow::Result<PathBuf> {
+    let hash = input.iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    fs::create_dir_all(dir)?;
+    let path = dir.join(format!("crash-{:016x}", hash));
+    fs::write(&path, input)?;
+
+    Ok(path)
+}
+
+/// The input being parsed, shared with the watchdog so that it can be saved
+/// if the target never returns.
+struct Progress {
+    input: Mutex<Vec<u8>>,
+    executions: AtomicU64,
+    busy: AtomicBool,
+}
+
+fn watch(progress: Arc<Progress>, timeout: Duration, crashes: PathBuf, name: &'static str) {
+    thread::spawn(move || loop {
+        let before = progress.executions.load(Ordering::SeqCst);
+        thread::sleep(timeout);
+
+        if progress.busy.load(Ordering::SeqCst)
+            && progress.executions.load(Ordering::SeqCst) == before
+        {
+            let input = progress.input.lock().map(|i| i.clone()).unwrap_or_default();
+            match save_crash(&crashes, &input) {
+                Ok(path) => eprintln!(
+                    "{}: took longer than {:?}; input saved to {}",
+                    name,
+                    timeout,
+                    path.display()
+                ),
+                Err(e) => eprintln!("{}: took longer than {:?} ({})", name, timeout, e),
+            }
+            process::exit(1);
+        }
+    });
+}
+
+fn run(cli: &Cli, year: &Year, target: &'static Target) -> anyhow::Result<Option<Crash>> {
+    let crashes = directory(&cli.crashes, year, target);
+    let progress = Arc::new(Progress {
+        input: Mutex::new(vec![]),
+        executions: AtomicU64::new(0),
+        busy: AtomicBool::new(false),
+    });
+    watch(
+        Arc::clone(&progress),
+        Duration::from_millis(cli.timeout),
+        crashes.clone(),
+        target.name,
+    );
+
+    let extra_seeds = read_corpus(&directory(&cli.corpus, year, target))?;
+    let mut rng = Rng::new(cli.seed);
+
+    let crash = fuzz::fuzz(target, extra_seeds, &mut rng, cli.iterations, |input| {
+        if let Ok(mut current) = progress.input.lock() {
+            current.clear();
+            current.extend_from_slice(input);
+        }
+        progress.busy.store(true, Ordering::SeqCst);
+
+        let (result, allocated) = allocations::peak(|| target.exec(input));
+
+        progress.busy.store(false, Ordering::SeqCst);
+        progress.executions.fetch_add(1, Ordering::SeqCst);
+
+        if allocated > allocation_limit(input.len()) {
+            return Err(format!(
+                "allocated {} bytes for a {} byte input",
+                allocated,
+                input.len()
+            ));
+        }
+        result
+    });
+
+    if let Some(crash) = &crash {
+        let path = save_crash(&crashes, &crash.input)?;
+        println!(
+            "{}: {}; input saved to {}",
+            target.name,
+            crash.message,
+            path.display()
+        );
+    }
+
+    Ok(crash)
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let year = find_year(cli.year)?;
+
+    if cli.list {
+        for target in year.fuzz_targets {
+            println!("{}", target.name);
+        }
+        return Ok(());
+    }
+
+    let targets = match &cli.target {
+        Some(name) => vec![year.fuzz_target(name)?],
+        None => year.fuzz_targets.iter().collect(),
+    };
+
+    // Panics are expected while fuzzing, and reported with the input instead.
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let mut crashed = false;
+    for target in targets {
+        let start = Instant::now();
+        match run(&cli, year, target)? {
+            Some(_) => crashed = true,
+            None => println!(
+                "{}: {} inputs without a crash ({:.2?})",
+                target.name,
+                cli.iterations,
+                start.elapsed()
+            ),
+        }
+    }
+
+    if crashed {
+        bail!("fuzzing found crashes");
+    }
+
+    Ok(())
+}
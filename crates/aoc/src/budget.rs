@@ -0,0 +1,288 @@
+//! Time budgets for each day and part, and checking the solvers keep to them.
+
+use crate::table::Table;
+use anyhow::{anyhow, bail, Context};
+use aoc_core::runner::Outcome;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt, str::FromStr, time::Duration};
+
+/// What a budget applies to, written `default`, `7` or `7.2` in `aoc.toml`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Scope {
+    Default,
+    Day(u8),
+    Part(u8, u8),
+}
+
+impl FromStr for Scope {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = |n: &str| {
+            n.parse()
+                .map_err(|_| anyhow!("budget is for an invalid day or part: '{}'", s))
+        };
+
+        match s.split_once('.') {
+            _ if s == "default" => Ok(Self::Default),
+            Some((day, part)) => Ok(Self::Part(number(day)?, number(part)?)),
+            None => Ok(Self::Day(number(s)?)),
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::Day(day) => write!(f, "{}", day),
+            Self::Part(day, part) => write!(f, "{}.{}", day, part),
+        }
+    }
+}
+
+const UNITS: [(&str, u128); 5] = [
+    ("ns", 1),
+    ("us", 1_000),
+    ("µs", 1_000),
+    ("ms", 1_000_000),
+    ("s", 1_000_000_000),
+];
+
+/// Parses a time such as `100ms`, `1.5s` or `250us`.
+pub fn parse_duration(s: &str) -> anyhow::Result<Duration> {
+    let invalid = || anyhow!("time is invalid: '{}'", s);
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .ok_or_else(invalid)?;
+    let (number, unit) = s.split_at(split);
+    let (_, scale) = UNITS
+        .iter()
+        .find(|(name, _)| *name == unit.trim())
+        .ok_or_else(invalid)?;
+    let number: f64 = number.parse().map_err(|_| invalid())?;
+
+    Ok(Duration::from_nanos((number * *scale as f64).round() as u64))
+}
+
+/// Writes a time in the largest unit that keeps it whole.
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    let (unit, scale) = UNITS
+        .iter()
+        .rev()
+        .filter(|(unit, _)| *unit != "µs")
+        .find(|(_, scale)| nanos.is_multiple_of(*scale))
+        .unwrap_or(&UNITS[0]);
+
+    format!("{}{}", nanos / scale, unit)
+}
+
+/// The budgets declared for each scope, where a part's own budget beats its
+/// day's, and its day's beats the default. A day's budget is for each of its
+/// parts, not for the parts together.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(
+    try_from = "BTreeMap<String, String>",
+    into = "BTreeMap<String, String>"
+)]
+pub struct Budgets(BTreeMap<Scope, Duration>);
+
+impl TryFrom<BTreeMap<String, String>> for Budgets {
+    type Error = anyhow::Error;
+
+    fn try_from(budgets: BTreeMap<String, String>) -> Result<Self, Self::Error> {
+        budgets
+            .iter()
+            .map(|(scope, time)| {
+                let time = parse_duration(time)
+                    .with_context(|| format!("budget for '{}' is invalid", scope))?;
+                Ok((scope.parse()?, time))
+            })
+            .collect::<anyhow::Result<_>>()
+            .map(Self)
+    }
+}
+
+impl From<Budgets> for BTreeMap<String, String> {
+    fn from(budgets: Budgets) -> Self {
+        budgets
+            .0
+            .into_iter()
+            .map(|(scope, time)| (scope.to_string(), format_duration(time)))
+            .collect()
+    }
+}
+
+impl Budgets {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn set(&mut self, scope: Scope, time: Duration) {
+        self.0.insert(scope, time);
+    }
+
+    /// These budgets, with any that `over` declares taking precedence.
+    pub fn merge(mut self, over: Self) -> Self {
+        self.0.extend(over.0);
+        self
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<Duration> {
+        [Scope::Part(day, part), Scope::Day(day), Scope::Default]
+            .iter()
+            .find_map(|scope| self.0.get(scope).copied())
+    }
+}
+
+/// How a part fared against its budget.
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub budget: Option<Duration>,
+}
+
+impl Check {
+    /// Whether the part failed, or took longer than its budget.
+    pub fn failed(&self) -> bool {
+        self.outcome.answer.is_err() || self.budget.is_some_and(|b| self.outcome.elapsed > b)
+    }
+}
+
+/// A row for each part, with how far over budget any that overran were.
+pub fn report(checks: &[Check]) -> Table {
+    let mut table = Table::new(["Day / Part", "Time", "Budget", "Result"]);
+    for check in checks {
+        let result = match (&check.outcome.answer, check.budget) {
+            (Err(e), _) => format!("error: {:#}", e),
+            (Ok(_), None) => "no budget".to_string(),
+            (Ok(_), Some(budget)) if check.outcome.elapsed > budget => format!(
+                "over by {:.1}x",
+                check.outcome.elapsed.as_secs_f64() / budget.as_secs_f64()
+            ),
+            (Ok(_), Some(_)) => "ok".to_string(),
+        };
+        table.push([
+            format!("Day {:02} / Part {}", check.day, check.part),
+            format!("{:.2?}", check.outcome.elapsed),
+            check
+                .budget
+                .map_or_else(|| "-".to_string(), format_duration),
+            result,
+        ]);
+    }
+
+    table
+}
+
+/// Fails if any part failed or went over budget.
+pub fn ensure_within(checks: &[Check]) -> anyhow::Result<()> {
+    let failed = checks.iter().filter(|c| c.failed()).count();
+    if failed > 0 {
+        bail!(
+            "{} of {} parts failed or went over budget",
+            failed,
+            checks.len()
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::answer::Answer;
+    use test_case::test_case;
+
+    #[test_case("100ms" => Duration::from_millis(100))]
+    #[test_case("1.5s" => Duration::from_millis(1500))]
+    #[test_case("250us" => Duration::from_micros(250))]
+    #[test_case("250µs" => Duration::from_micros(250))]
+    #[test_case("7 ns" => Duration::from_nanos(7))]
+    fn parses_durations(s: &str) -> Duration {
+        parse_duration(s).expect("duration must be valid")
+    }
+
+    #[test_case("100")]
+    #[test_case("ms")]
+    #[test_case("1.2.3ms")]
+    #[test_case("5 minutes")]
+    fn rejects_invalid_durations(s: &str) {
+        assert!(parse_duration(s).is_err());
+    }
+
+    #[test]
+    fn prefers_the_narrowest_budget() -> anyhow::Result<()> {
+        let budgets = Budgets::try_from(BTreeMap::from([
+            ("default".to_string(), "100ms".to_string()),
+            ("7".to_string(), "10ms".to_string()),
+            ("7.2".to_string(), "1ms".to_string()),
+        ]))?;
+
+        assert_eq!(budgets.get(1, 1), Some(Duration::from_millis(100)));
+        assert_eq!(budgets.get(7, 1), Some(Duration::from_millis(10)));
+        assert_eq!(budgets.get(7, 2), Some(Duration::from_millis(1)));
+        assert_eq!(Budgets::default().get(7, 2), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_budgets() {
+        let budgets = |scope: &str, time: &str| {
+            Budgets::try_from(BTreeMap::from([(scope.to_string(), time.to_string())]))
+        };
+
+        assert!(budgets("7.x", "1ms").is_err());
+        assert!(budgets("seven", "1ms").is_err());
+        assert!(budgets("7", "soon").is_err());
+    }
+
+    #[test]
+    fn writes_budgets_as_they_were_declared() {
+        let mut budgets = Budgets::default();
+        budgets.set(Scope::Default, Duration::from_millis(100));
+        budgets.set(Scope::Part(4, 2), Duration::from_micros(1500));
+
+        assert_eq!(
+            BTreeMap::from(budgets),
+            BTreeMap::from([
+                ("4.2".to_string(), "1500us".to_string()),
+                ("default".to_string(), "100ms".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn reports_parts_over_budget() {
+        let check = |part, millis, budget: Option<u64>| Check {
+            day: 7,
+            part,
+            outcome: Outcome {
+                answer: Ok(Answer::Int(1)),
+                elapsed: Duration::from_millis(millis),
+            },
+            budget: budget.map(Duration::from_millis),
+        };
+        let checks = [
+            check(1, 5, Some(10)),
+            check(2, 25, Some(10)),
+            check(2, 25, None),
+        ];
+
+        let results: Vec<_> = report(&checks)
+            .rows
+            .into_iter()
+            .map(|row| row[3].clone())
+            .collect();
+        assert_eq!(results, ["ok", "over by 2.5x", "no budget"]);
+        assert!(ensure_within(&checks[..1]).is_ok());
+        assert_eq!(
+            ensure_within(&checks).unwrap_err().to_string(),
+            "1 of 3 parts failed or went over budget"
+        );
+    }
+}
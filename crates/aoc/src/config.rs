@@ -0,0 +1,443 @@
+//! Settings from `aoc.toml`: the user's, in their config directory, and the
+//! repo's, found by searching up from the working directory. The repo's
+//! settings win, `AOC_*` environment variables win over both, and command
+//! line flags win over everything.
+
+use crate::{
+    budget::{self, Budgets, Scope},
+    oracle::Oracles,
+    table::Format,
+};
+use anyhow::{anyhow, Context};
+use aoc_core::{
+    input,
+    solutions::{Day, InputKind},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+pub const FILE_NAME: &str = "aoc.toml";
+
+/// A set of puzzle inputs other than the ones the year crates embed, such as
+/// a teammate's.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    /// The directory holding `day_NN.txt` for each day.
+    pub inputs: PathBuf,
+}
+
+impl Profile {
+    pub fn input(&self, day: u8) -> PathBuf {
+        self.inputs.join(format!("day_{:02}.txt", day))
+    }
+}
+
+/// The settings from one file, or from the environment, any of which may be
+/// left for another layer to set.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Layer {
+    resources: Option<String>,
+    session_file: Option<PathBuf>,
+    format: Option<Format>,
+    iterations: Option<usize>,
+    timeout: Option<u64>,
+    profile: Option<String>,
+    #[serde(default)]
+    profiles: BTreeMap<String, Profile>,
+    #[serde(default)]
+    budgets: Budgets,
+    #[serde(default)]
+    oracles: Oracles,
+}
+
+impl Layer {
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        Ok(toml::from_str(text)?)
+    }
+
+    fn read(path: &Path) -> anyhow::Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+
+        let layer = Self::parse(&text).with_context(|| format!("{} is invalid", path.display()))?;
+
+        Ok(layer.relative_to(path.parent().unwrap_or(Path::new(""))))
+    }
+
+    /// This layer, with its relative paths taken to be relative to `dir`, the
+    /// directory of the file that set them, rather than to wherever `aoc` was
+    /// run from.
+    fn relative_to(mut self, dir: &Path) -> Self {
+        let resolve = |path: &Path| dir.join(expand_home(path));
+
+        self.resources = self
+            .resources
+            .map(|resources| resolve(Path::new(&resources)).display().to_string());
+        self.session_file = self.session_file.as_deref().map(resolve);
+        for profile in self.profiles.values_mut() {
+            profile.inputs = resolve(&profile.inputs);
+        }
+
+        self
+    }
+
+    /// The settings in `AOC_RESOURCES`, `AOC_SESSION_FILE`, `AOC_FORMAT`,
+    /// `AOC_ITERATIONS`, `AOC_TIMEOUT` and `AOC_PROFILE`, and the default
+    /// budget in `AOC_BUDGET`.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> anyhow::Result<Self> {
+        fn parse<T: std::str::FromStr>(
+            name: &str,
+            value: Option<String>,
+        ) -> anyhow::Result<Option<T>> {
+            value
+                .map(|value| {
+                    value
+                        .parse()
+                        .map_err(|_| anyhow!("{} is invalid: '{}'", name, value))
+                })
+                .transpose()
+        }
+
+        Ok(Self {
+            resources: var("AOC_RESOURCES"),
+            session_file: var("AOC_SESSION_FILE").map(PathBuf::from),
+            format: parse("AOC_FORMAT", var("AOC_FORMAT"))?,
+            iterations: parse("AOC_ITERATIONS", var("AOC_ITERATIONS"))?,
+            timeout: parse("AOC_TIMEOUT", var("AOC_TIMEOUT"))?,
+            profile: var("AOC_PROFILE"),
+            profiles: BTreeMap::new(),
+            budgets: match var("AOC_BUDGET") {
+                Some(time) => {
+                    let mut budgets = Budgets::default();
+                    let time = budget::parse_duration(&time).context("AOC_BUDGET is invalid")?;
+                    budgets.set(Scope::Default, time);
+                    budgets
+                }
+                None => Budgets::default(),
+            },
+            oracles: Oracles::default(),
+        })
+    }
+
+    /// This layer, with anything `over` sets taking precedence.
+    pub fn merge(mut self, over: Self) -> Self {
+        self.profiles.extend(over.profiles);
+
+        Self {
+            resources: over.resources.or(self.resources),
+            session_file: over.session_file.or(self.session_file),
+            format: over.format.or(self.format),
+            iterations: over.iterations.or(self.iterations),
+            timeout: over.timeout.or(self.timeout),
+            profile: over.profile.or(self.profile),
+            profiles: self.profiles,
+            budgets: self.budgets.merge(over.budgets),
+            oracles: self.oracles.merge(over.oracles),
+        }
+    }
+}
+
+/// The effective settings, with defaults for anything no layer set.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Config {
+    /// Where each year's `day_NN` resource directories go, with `{year}`
+    /// standing for the year.
+    pub resources: String,
+    /// A file holding the site's session cookie.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_file: Option<PathBuf>,
+    pub format: Format,
+    /// How many times benchmarks run each solver.
+    pub iterations: usize,
+    /// How many seconds to wait for the site, or for an oracle to answer.
+    pub timeout: u64,
+    /// The profile whose inputs replace the puzzle inputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Profile>,
+    /// How long each day and part may take.
+    #[serde(skip_serializing_if = "Budgets::is_empty")]
+    pub budgets: Budgets,
+    /// Commands that answer each day and part, to cross-check with.
+    #[serde(skip_serializing_if = "Oracles::is_empty")]
+    pub oracles: Oracles,
+}
+
+impl From<Layer> for Config {
+    fn from(layer: Layer) -> Self {
+        Self {
+            resources: layer
+                .resources
+                .unwrap_or_else(|| "crates/aoc-{year}/resources".to_string()),
+            session_file: layer.session_file.map(|path| expand_home(&path)),
+            format: layer.format.unwrap_or_default(),
+            iterations: layer.iterations.unwrap_or(10),
+            timeout: layer.timeout.unwrap_or(30),
+            profile: layer.profile,
+            profiles: layer.profiles,
+            budgets: layer.budgets,
+            oracles: layer.oracles,
+        }
+    }
+}
+
+/// Expands a leading `~` to the home directory.
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), env::var_os("HOME")) {
+        (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Where the user's `aoc.toml` lives.
+fn user_file() -> Option<PathBuf> {
+    let dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))?;
+
+    Some(dir.join("aoc").join(FILE_NAME))
+}
+
+/// The nearest `aoc.toml` in `dir` or its ancestors.
+fn repo_file(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join(FILE_NAME))
+        .find(|path| path.is_file())
+}
+
+impl Config {
+    /// Merges the user's and the repo's files with the environment, returning
+    /// the files that were read too.
+    pub fn load() -> anyhow::Result<(Self, Vec<PathBuf>)> {
+        let files: Vec<_> = [user_file(), repo_file(&env::current_dir()?)]
+            .into_iter()
+            .flatten()
+            .filter(|path| path.is_file())
+            .collect();
+
+        let mut layer = Layer::default();
+        for path in &files {
+            layer = layer.merge(Layer::read(path)?);
+        }
+        layer = layer.merge(Layer::from_env(|name| env::var(name).ok())?);
+
+        Ok((layer.into(), files))
+    }
+
+    /// The resources directory for a year's day.
+    pub fn day_resources(&self, year: u16, day: u8) -> PathBuf {
+        PathBuf::from(self.resources.replace("{year}", &year.to_string()))
+            .join(format!("day_{:02}", day))
+    }
+
+    /// The profile with the given name, or the configured one.
+    pub fn profile(&self, name: Option<&str>) -> anyhow::Result<Option<&Profile>> {
+        name.or(self.profile.as_deref())
+            .map(|name| {
+                self.profiles
+                    .get(name)
+                    .ok_or_else(|| anyhow!("there is no profile '{}'", name))
+            })
+            .transpose()
+    }
+
+    /// The file to read a day's input from instead of the one its crate
+    /// embeds: the selected profile's, for puzzle inputs.
+    pub fn input_path(&self, day: &Day, kind: InputKind) -> anyhow::Result<Option<PathBuf>> {
+        Ok(match kind {
+            InputKind::Example => None,
+            InputKind::Puzzle => self.profile(None)?.map(|profile| profile.input(day.number)),
+        })
+    }
+
+    /// A day's input, read from the selected profile if there is one.
+    pub fn input(&self, day: &Day, kind: InputKind) -> anyhow::Result<Cow<'static, str>> {
+        match self.input_path(day, kind)? {
+            Some(path) => Ok(Cow::Owned(input::read_to_string(&path)?)),
+            None => Ok(Cow::Borrowed(day.input(kind))),
+        }
+    }
+
+    /// The session cookie stored in `session_file`, if there is one.
+    pub fn session(&self) -> anyhow::Result<Option<String>> {
+        self.session_file
+            .as_deref()
+            .map(|path| {
+                fs::read_to_string(path)
+                    .map(|session| session.trim().to_string())
+                    .with_context(|| format!("failed to read {}", path.display()))
+            })
+            .transpose()
+    }
+
+    /// The settings as TOML, as they'd be written in `aoc.toml`.
+    pub fn to_toml(&self) -> anyhow::Result<String> {
+        Ok(toml::to_string(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    const USER: &str = r#"
+        session_file = "/home/ada/.config/aoc/session"
+        iterations = 100
+
+        [profiles.ada]
+        inputs = "/home/ada/aoc"
+    "#;
+
+    const REPO: &str = r#"
+        resources = "resources/{year}"
+        format = "markdown"
+        iterations = 20
+
+        [profiles.grace]
+        inputs = "inputs/grace"
+
+        [budgets]
+        default = "100ms"
+        "7.2" = "5ms"
+
+        [oracles]
+        "7" = "./crabs --part {part}"
+    "#;
+
+    #[test]
+    fn defaults_anything_unset() {
+        let config = Config::from(Layer::default());
+
+        assert_eq!(config.format, Format::Table);
+        assert_eq!(config.iterations, 10);
+        assert_eq!(
+            config.day_resources(2021, 1),
+            Path::new("crates/aoc-2021/resources/day_01")
+        );
+    }
+
+    #[test]
+    fn layers_override_in_order() -> anyhow::Result<()> {
+        let env = Layer::from_env(|name| match name {
+            "AOC_ITERATIONS" => Some("5".to_string()),
+            "AOC_PROFILE" => Some("ada".to_string()),
+            "AOC_BUDGET" => Some("50ms".to_string()),
+            _ => None,
+        })?;
+        let config = Config::from(Layer::parse(USER)?.merge(Layer::parse(REPO)?).merge(env));
+
+        assert_eq!(
+            config.day_resources(2021, 7),
+            Path::new("resources/2021/day_07")
+        );
+        assert_eq!(
+            config.session_file.as_deref(),
+            Some(Path::new("/home/ada/.config/aoc/session"))
+        );
+        assert_eq!(config.format, Format::Markdown);
+        assert_eq!(config.iterations, 5);
+        assert_eq!(
+            config.profile(None)?.map(|p| p.input(3)),
+            Some(PathBuf::from("/home/ada/aoc/day_03.txt"))
+        );
+        assert_eq!(
+            config.profile(Some("grace"))?.map(|p| p.input(3)),
+            Some(PathBuf::from("inputs/grace/day_03.txt"))
+        );
+        assert!(config.profile(Some("linus")).is_err());
+        assert_eq!(config.budgets.get(1, 1), Some(Duration::from_millis(50)));
+        assert_eq!(config.budgets.get(7, 2), Some(Duration::from_millis(5)));
+        assert_eq!(
+            config.oracles.get(7, 2).as_deref(),
+            Some("./crabs --part 2")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolves_paths_against_their_file() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-config-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        let path = dir.join(FILE_NAME);
+        fs::write(&path, format!("session_file = \"session\"\n{}", REPO))?;
+
+        let layer = Layer::read(&path);
+        fs::remove_dir_all(&dir)?;
+        let config = Config::from(Layer::parse(USER)?.merge(layer?));
+
+        assert_eq!(
+            config.day_resources(2021, 7),
+            dir.join("resources/2021/day_07")
+        );
+        assert_eq!(config.session_file, Some(dir.join("session")));
+        assert_eq!(
+            config.profile(Some("grace"))?.map(|p| p.input(3)),
+            Some(dir.join("inputs/grace/day_03.txt"))
+        );
+        assert_eq!(
+            config.profile(Some("ada"))?.map(|p| p.input(3)),
+            Some(PathBuf::from("/home/ada/aoc/day_03.txt"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reads_puzzle_inputs_from_the_selected_profile() -> anyhow::Result<()> {
+        let day = &aoc_2021::YEAR.days[0];
+        let dir = std::env::temp_dir().join(format!("aoc-profile-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join("day_01.txt"), "1\n2\n")?;
+
+        let mut config = Config::from(Layer::default());
+        let embedded = config.input(day, InputKind::Puzzle)?;
+        config.profile = Some("grace".to_string());
+        config.profiles.insert(
+            "grace".to_string(),
+            Profile {
+                inputs: dir.clone(),
+            },
+        );
+        let example = config.input(day, InputKind::Example)?;
+        let puzzle = config.input(day, InputKind::Puzzle);
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(embedded, day.puzzle_input);
+        assert_eq!(example, day.example_input);
+        assert_eq!(puzzle?, "1\n2\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        assert!(Layer::parse("iterations = \"many\"").is_err());
+        assert!(Layer::parse("format = \"html\"").is_err());
+        assert!(Layer::parse("colour = \"red\"").is_err());
+        assert!(Layer::from_env(|_| Some("soon".to_string())).is_err());
+    }
+
+    #[test]
+    fn shows_what_it_reads() -> anyhow::Result<()> {
+        let config = Config::from(Layer::parse(REPO)?);
+        let shown = config.to_toml()?;
+
+        assert_eq!(Config::from(Layer::parse(&shown)?), config);
+        assert!(shown.contains("format = \"markdown\""));
+        assert!(shown.contains("\"7.2\" = \"5ms\""));
+        assert!(shown.contains("7 = \"./crabs --part {part}\""));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,309 @@
+mod gif;
+mod png;
+
+use anyhow::bail;
+use aoc_core::visualise::{Colour, Frame};
+use std::{fs::File, io::BufWriter, path::Path, str::FromStr};
+
+pub type Rgb = [u8; 3];
+
+/// Width and height of a character cell, in unscaled image pixels.
+const CELL_WIDTH: usize = 4;
+const CELL_HEIGHT: usize = 6;
+
+/// 3×5 glyphs for the digits, one row per byte with the leftmost pixel in
+/// the highest of the three low bits.
+const DIGITS: [[u8; 5]; 10] = [
+    [0b111, 0b101, 0b101, 0b101, 0b111],
+    [0b010, 0b110, 0b010, 0b010, 0b111],
+    [0b111, 0b001, 0b111, 0b100, 0b111],
+    [0b111, 0b001, 0b111, 0b001, 0b111],
+    [0b101, 0b101, 0b111, 0b001, 0b001],
+    [0b111, 0b100, 0b111, 0b001, 0b111],
+    [0b111, 0b100, 0b111, 0b101, 0b111],
+    [0b111, 0b001, 0b010, 0b010, 0b010],
+    [0b111, 0b101, 0b111, 0b101, 0b111],
+    [0b111, 0b101, 0b111, 0b001, 0b111],
+];
+
+/// 3×5 glyphs for the letters, which are all drawn as capitals.
+const LETTERS: [[u8; 5]; 26] = [
+    [0b010, 0b101, 0b111, 0b101, 0b101],
+    [0b110, 0b101, 0b110, 0b101, 0b110],
+    [0b011, 0b100, 0b100, 0b100, 0b011],
+    [0b110, 0b101, 0b101, 0b101, 0b110],
+    [0b111, 0b100, 0b110, 0b100, 0b111],
+    [0b111, 0b100, 0b110, 0b100, 0b100],
+    [0b011, 0b100, 0b101, 0b101, 0b011],
+    [0b101, 0b101, 0b111, 0b101, 0b101],
+    [0b111, 0b010, 0b010, 0b010, 0b111],
+    [0b001, 0b001, 0b001, 0b101, 0b010],
+    [0b101, 0b101, 0b110, 0b101, 0b101],
+    [0b100, 0b100, 0b100, 0b100, 0b111],
+    [0b101, 0b111, 0b111, 0b101, 0b101],
+    [0b110, 0b101, 0b101, 0b101, 0b101],
+    [0b010, 0b101, 0b101, 0b101, 0b010],
+    [0b110, 0b101, 0b110, 0b100, 0b100],
+    [0b010, 0b101, 0b101, 0b110, 0b011],
+    [0b110, 0b101, 0b110, 0b101, 0b101],
+    [0b011, 0b100, 0b010, 0b001, 0b110],
+    [0b111, 0b010, 0b010, 0b010, 0b010],
+    [0b101, 0b101, 0b101, 0b101, 0b111],
+    [0b101, 0b101, 0b101, 0b101, 0b010],
+    [0b101, 0b101, 0b111, 0b111, 0b101],
+    [0b101, 0b101, 0b010, 0b101, 0b101],
+    [0b101, 0b101, 0b010, 0b010, 0b010],
+    [0b111, 0b001, 0b010, 0b100, 0b111],
+];
+
+/// The 3×5 glyph for a character, if there is one. Anything else, such as
+/// `█`, fills its cell.
+fn glyph(ch: char) -> Option<[u8; 5]> {
+    Some(match ch.to_ascii_uppercase() {
+        ch @ '0'..='9' => DIGITS[ch as usize - '0' as usize],
+        ch @ 'A'..='Z' => LETTERS[ch as usize - 'A' as usize],
+        ':' => [0b000, 0b010, 0b000, 0b010, 0b000],
+        ',' => [0b000, 0b000, 0b000, 0b010, 0b100],
+        '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+        '(' => [0b001, 0b010, 0b010, 0b010, 0b001],
+        ')' => [0b100, 0b010, 0b010, 0b010, 0b100],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        '/' => [0b001, 0b001, 0b010, 0b100, 0b100],
+        '@' => [0b010, 0b101, 0b111, 0b100, 0b011],
+        _ => return None,
+    })
+}
+
+/// The colours used for a frame's background and each of its `Colour`s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Palette {
+    Dark,
+    Light,
+}
+
+impl Palette {
+    fn colours(self) -> [Rgb; 8] {
+        // Background, then each `Colour` in declaration order.
+        match self {
+            Self::Dark => [
+                [0x0f, 0x0f, 0x23],
+                [0xcc, 0xcc, 0xcc],
+                [0x55, 0x55, 0x66],
+                [0xff, 0x55, 0x55],
+                [0x00, 0xcc, 0x00],
+                [0xff, 0xff, 0x66],
+                [0x66, 0x99, 0xff],
+                [0x00, 0xcc, 0xcc],
+            ],
+            Self::Light => [
+                [0xff, 0xff, 0xff],
+                [0x20, 0x20, 0x20],
+                [0xaa, 0xaa, 0xaa],
+                [0xcc, 0x00, 0x00],
+                [0x00, 0x88, 0x00],
+                [0xcc, 0x99, 0x00],
+                [0x00, 0x44, 0xcc],
+                [0x00, 0x88, 0x88],
+            ],
+        }
+    }
+}
+
+impl FromStr for Palette {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dark" => Ok(Self::Dark),
+            "light" => Ok(Self::Light),
+            _ => bail!("palette is invalid: '{}'", s),
+        }
+    }
+}
+
+fn palette_index(colour: Colour) -> u8 {
+    match colour {
+        Colour::Plain => 1,
+        Colour::Dim => 2,
+        Colour::Red => 3,
+        Colour::Green => 4,
+        Colour::Yellow => 5,
+        Colour::Blue => 6,
+        Colour::Cyan => 7,
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Options {
+    pub palette: Palette,
+    pub scale: usize,
+    /// Delay between animation frames, in hundredths of a second.
+    pub delay: u16,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            palette: Palette::Dark,
+            scale: 2,
+            delay: 10,
+        }
+    }
+}
+
+/// An image as indices into a palette of at most 256 colours.
+struct Indexed {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Indexed {
+    /// Draws a frame's grid, and its caption, if it has one, along the bottom
+    /// row of cells.
+    fn rasterise(frame: &Frame, width: usize, height: usize, scale: usize) -> Self {
+        let scale = scale.max(1);
+        let mut indexed = Self {
+            width: width * CELL_WIDTH * scale,
+            height: height * CELL_HEIGHT * scale,
+            pixels: vec![0; width * CELL_WIDTH * scale * height * CELL_HEIGHT * scale],
+        };
+
+        for y in 0..frame.height() {
+            for x in 0..frame.width() {
+                let pixel = frame.get(x, y);
+                indexed.cell(x, y, pixel.ch, palette_index(pixel.colour), scale);
+            }
+        }
+        if !frame.caption.is_empty() {
+            let index = palette_index(Colour::Plain);
+            for (x, ch) in frame.caption.chars().take(width).enumerate() {
+                indexed.cell(x, height - 1, ch, index, scale);
+            }
+        }
+
+        indexed
+    }
+
+    fn cell(&mut self, x: usize, y: usize, ch: char, index: u8, scale: usize) {
+        for gy in 0..CELL_HEIGHT {
+            for gx in 0..CELL_WIDTH {
+                if lit(ch, gx, gy) {
+                    self.fill(
+                        (x * CELL_WIDTH + gx) * scale,
+                        (y * CELL_HEIGHT + gy) * scale,
+                        scale,
+                        index,
+                    );
+                }
+            }
+        }
+    }
+
+    fn fill(&mut self, x: usize, y: usize, size: usize, index: u8) {
+        for py in y..y + size {
+            let row = py * self.width;
+            self.pixels[row + x..row + x + size].fill(index);
+        }
+    }
+}
+
+/// Whether a glyph pixel is lit, leaving the rightmost column and bottom row
+/// of each glyph's cell empty so that neighbouring characters stay legible.
+fn lit(ch: char, x: usize, y: usize) -> bool {
+    match (ch, glyph(ch)) {
+        (' ', _) => false,
+        (_, Some(glyph)) => x < 3 && y < 5 && glyph[y] & (0b100 >> x) != 0,
+        (_, None) => true,
+    }
+}
+
+/// The cells needed for the largest frame, and a row beneath for captions if
+/// any frame has one.
+fn bounds(frames: &[Frame]) -> (usize, usize) {
+    let captions = frames.iter().map(|frame| frame.caption.chars().count());
+    let width = frames.iter().map(Frame::width).chain(captions).max();
+    let height = frames.iter().map(Frame::height).max().unwrap_or(0)
+        + usize::from(frames.iter().any(|frame| !frame.caption.is_empty()));
+
+    (width.unwrap_or(0).max(1), height.max(1))
+}
+
+/// Writes a single frame as a PNG.
+pub fn write_png(path: &Path, frame: &Frame, options: Options) -> anyhow::Result<()> {
+    let (width, height) = bounds(std::slice::from_ref(frame));
+    let image = Indexed::rasterise(frame, width, height, options.scale);
+    let mut out = BufWriter::new(File::create(path)?);
+
+    png::encode(&mut out, &image, &options.palette.colours())?;
+
+    Ok(())
+}
+
+/// Writes frames as a looping, animated GIF, sized to fit the largest frame.
+pub fn write_gif(path: &Path, frames: &[Frame], options: Options) -> anyhow::Result<()> {
+    if frames.is_empty() {
+        bail!("there are no frames to export");
+    }
+
+    let (width, height) = bounds(frames);
+    let images: Vec<Indexed> = frames
+        .iter()
+        .map(|frame| Indexed::rasterise(frame, width, height, options.scale))
+        .collect();
+    let mut out = BufWriter::new(File::create(path)?);
+
+    gif::encode(&mut out, &images, &options.palette.colours(), options.delay)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rasterise_scales_cells() {
+        let mut frame = Frame::new(2, 1);
+        frame.set(0, 0, '#', Colour::Red);
+
+        let image = Indexed::rasterise(&frame, 2, 1, 2);
+
+        assert_eq!((image.width, image.height), (16, 12));
+        assert_eq!(image.pixels[0], palette_index(Colour::Red));
+        assert_eq!(image.pixels[7], palette_index(Colour::Red));
+        assert_eq!(image.pixels[8], 0);
+    }
+
+    #[test]
+    fn captions_go_beneath_the_grid() {
+        let frames = [Frame::new(2, 2).with_caption("Day 1"), Frame::new(3, 1)];
+        assert_eq!(bounds(&frames), (5, 3));
+
+        let image = Indexed::rasterise(&frames[0], 5, 3, 1);
+        // The top left of the D, and the gap in its middle.
+        let row = 2 * CELL_HEIGHT * image.width;
+        assert_eq!(image.pixels[row], palette_index(Colour::Plain));
+        assert_eq!(image.pixels[row + image.width + 1], 0);
+        assert_eq!(bounds(&frames[1..]), (3, 1));
+    }
+
+    #[test]
+    fn letters_and_punctuation_have_glyphs() {
+        assert!(lit('A', 1, 0));
+        assert!(!lit('A', 0, 0));
+        assert!(!lit('a', 0, 0));
+        assert!(lit(':', 1, 1));
+        assert!(!lit(':', 1, 2));
+        assert!(!lit('.', 0, 0));
+        assert!(lit('█', 3, 5));
+    }
+
+    #[test]
+    fn digits_leave_a_gap() {
+        assert!(lit('1', 1, 0));
+        assert!(!lit('1', 0, 0));
+        assert!(!lit('8', 3, 0));
+        assert!(!lit('8', 0, 5));
+    }
+}
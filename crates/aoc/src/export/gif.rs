@@ -0,0 +1,237 @@
+use super::{Indexed, Rgb};
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
+
+const MAX_CODE: u16 = 4096;
+
+/// Encodes indexed images as a GIF that loops forever, showing each image
+/// for `delay` hundredths of a second.
+pub(super) fn encode(
+    out: &mut impl Write,
+    images: &[Indexed],
+    palette: &[Rgb],
+    delay: u16,
+) -> io::Result<()> {
+    let width = images.iter().map(|i| i.width).max().unwrap_or(0) as u16;
+    let height = images.iter().map(|i| i.height).max().unwrap_or(0) as u16;
+
+    // The global colour table holds a power of two colours, at least two.
+    let table_bits = palette.len().next_power_of_two().trailing_zeros().max(1);
+    let min_code_size = table_bits.max(2) as u8;
+
+    out.write_all(b"GIF89a")?;
+    out.write_all(&width.to_le_bytes())?;
+    out.write_all(&height.to_le_bytes())?;
+    // Global colour table present, 8 bits per channel, and the table's size.
+    out.write_all(&[0xf0 | (table_bits as u8 - 1), 0, 0])?;
+    for i in 0..1 << table_bits {
+        out.write_all(&palette.get(i).copied().unwrap_or_default())?;
+    }
+
+    // The NETSCAPE2.0 application extension, looping forever.
+    out.write_all(&[0x21, 0xff, 0x0b])?;
+    out.write_all(b"NETSCAPE2.0")?;
+    out.write_all(&[0x03, 0x01, 0x00, 0x00, 0x00])?;
+
+    for image in images {
+        // Graphic control extension, carrying the frame delay.
+        out.write_all(&[0x21, 0xf9, 0x04, 0x00])?;
+        out.write_all(&delay.to_le_bytes())?;
+        out.write_all(&[0x00, 0x00])?;
+
+        // Image descriptor, covering the whole screen with no local table.
+        out.write_all(&[0x2c, 0, 0, 0, 0])?;
+        out.write_all(&(image.width as u16).to_le_bytes())?;
+        out.write_all(&(image.height as u16).to_le_bytes())?;
+        out.write_all(&[0x00])?;
+
+        out.write_all(&[min_code_size])?;
+        for block in lzw(&image.pixels, min_code_size).chunks(255) {
+            out.write_all(&[block.len() as u8])?;
+            out.write_all(block)?;
+        }
+        out.write_all(&[0x00])?;
+    }
+
+    out.write_all(&[0x3b])
+}
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u8,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        Self {
+            bytes: vec![],
+            buffer: 0,
+            bits: 0,
+        }
+    }
+
+    fn write(&mut self, code: u16, size: u8) {
+        self.buffer |= u32::from(code) << self.bits;
+        self.bits += size;
+
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+
+        self.bytes
+    }
+}
+
+/// Compresses pixels with GIF's variable-width LZW.
+fn lzw(pixels: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1 << min_code_size;
+    let end = clear + 1;
+
+    let mut writer = BitWriter::new();
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next = end + 1;
+    let mut size = min_code_size + 1;
+
+    writer.write(clear, size);
+
+    let mut pixels = pixels.iter().copied();
+    let Some(first) = pixels.next() else {
+        writer.write(end, size);
+        return writer.finish();
+    };
+    let mut prefix = u16::from(first);
+
+    for pixel in pixels {
+        if let Some(&code) = table.get(&(prefix, pixel)) {
+            prefix = code;
+            continue;
+        }
+
+        writer.write(prefix, size);
+
+        if next < MAX_CODE {
+            table.insert((prefix, pixel), next);
+            // The decoder widens its codes once the next code no longer fits.
+            if next == 1 << size {
+                size += 1;
+            }
+            next += 1;
+        } else {
+            writer.write(clear, size);
+            table.clear();
+            next = end + 1;
+            size = min_code_size + 1;
+        }
+
+        prefix = u16::from(pixel);
+    }
+
+    writer.write(prefix, size);
+    writer.write(end, size);
+
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A straightforward decoder, to check the encoder against.
+    fn unlzw(data: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1u16 << min_code_size;
+        let end = clear + 1;
+
+        let mut codes = vec![];
+        let (mut buffer, mut bits, mut size) = (0u32, 0u8, min_code_size + 1);
+        let mut table: Vec<Vec<u8>> = vec![];
+        let mut prev: Option<Vec<u8>> = None;
+        let mut bytes = data.iter();
+
+        loop {
+            while bits < size {
+                buffer |= u32::from(*bytes.next().expect("ran out of data")) << bits;
+                bits += 8;
+            }
+            let code = (buffer & ((1 << size) - 1)) as u16;
+            buffer >>= size;
+            bits -= size;
+
+            if code == clear {
+                table = (0..clear).map(|i| vec![i as u8]).collect();
+                table.extend([vec![], vec![]]);
+                size = min_code_size + 1;
+                prev = None;
+                continue;
+            }
+            if code == end {
+                return codes;
+            }
+
+            let entry = match (table.get(usize::from(code)), &prev) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(prev)) => [prev.clone(), vec![prev[0]]].concat(),
+                (None, None) => panic!("unknown code {}", code),
+            };
+            if let Some(prev) = prev {
+                if table.len() < usize::from(MAX_CODE) {
+                    table.push([prev, vec![entry[0]]].concat());
+                    if table.len() == 1 << size && size < 12 {
+                        size += 1;
+                    }
+                }
+            }
+
+            codes.extend(&entry);
+            prev = Some(entry);
+        }
+    }
+
+    #[test]
+    fn lzw_round_trips() {
+        let pixels: Vec<u8> = (0..20_000u32)
+            .map(|i| ((i * i) % 5 + i % 3) as u8)
+            .collect();
+
+        let decoded = unlzw(&lzw(&pixels, 3), 3);
+
+        assert_eq!(decoded, pixels);
+    }
+
+    #[test]
+    fn lzw_round_trips_runs() {
+        let pixels = vec![5; 100_000];
+
+        let decoded = unlzw(&lzw(&pixels, 3), 3);
+
+        assert_eq!(decoded, pixels);
+    }
+
+    #[test]
+    fn encodes_header_and_trailer() -> io::Result<()> {
+        let image = Indexed {
+            width: 2,
+            height: 2,
+            pixels: vec![0, 1, 1, 0],
+        };
+        let mut out = vec![];
+
+        encode(&mut out, &[image], &[[0, 0, 0], [255, 255, 255]], 10)?;
+
+        assert_eq!(&out[..6], b"GIF89a");
+        assert_eq!(out[6..10], [2, 0, 2, 0]);
+        assert_eq!(out.last(), Some(&0x3b));
+
+        Ok(())
+    }
+}
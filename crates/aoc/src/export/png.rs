@@ -0,0 +1,169 @@
+use super::{Indexed, Rgb};
+use std::io::{self, Write};
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Deflate's stored blocks hold at most this many bytes.
+const MAX_STORED_BLOCK: usize = 65_535;
+
+/// Encodes an indexed image as an 8-bit paletted PNG.
+///
+/// The image data is wrapped in uncompressed deflate blocks: the frames are
+/// small enough that compression isn't worth an encoder of its own.
+pub(super) fn encode(out: &mut impl Write, image: &Indexed, palette: &[Rgb]) -> io::Result<()> {
+    out.write_all(&SIGNATURE)?;
+
+    let mut header = vec![];
+    header.extend((image.width as u32).to_be_bytes());
+    header.extend((image.height as u32).to_be_bytes());
+    // Bit depth, colour type (indexed), compression, filter and interlace.
+    header.extend([8, 3, 0, 0, 0]);
+    chunk(out, b"IHDR", &header)?;
+
+    chunk(out, b"PLTE", &palette.concat())?;
+
+    let mut raw = Vec::with_capacity((image.width + 1) * image.height);
+    for row in image.pixels.chunks(image.width.max(1)) {
+        // Each scanline starts with its filter type, which is always "none".
+        raw.push(0);
+        raw.extend(row);
+    }
+    chunk(out, b"IDAT", &zlib_stored(&raw))?;
+
+    chunk(out, b"IEND", &[])
+}
+
+fn chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+
+    let mut crc = Crc32::new();
+    crc.update(kind);
+    crc.update(data);
+    out.write_all(&crc.finish().to_be_bytes())
+}
+
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32K window and no preset dictionary, at the lowest level.
+    let mut out = vec![0x78, 0x01];
+
+    let mut blocks = data.chunks(MAX_STORED_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+
+        out.push(u8::from(last));
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+
+    out.extend(adler32(data).to_be_bytes());
+
+    out
+}
+
+struct Crc32 {
+    table: [u32; 256],
+    value: u32,
+}
+
+impl Crc32 {
+    fn new() -> Self {
+        let mut table = [0; 256];
+        for (n, entry) in table.iter_mut().enumerate() {
+            let mut c = n as u32;
+            for _ in 0..8 {
+                c = if c & 1 == 1 {
+                    0xedb8_8320 ^ (c >> 1)
+                } else {
+                    c >> 1
+                };
+            }
+            *entry = c;
+        }
+
+        Self {
+            table,
+            value: 0xffff_ffff,
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.value =
+                self.table[((self.value ^ u32::from(byte)) & 0xff) as usize] ^ (self.value >> 8);
+        }
+    }
+
+    fn finish(&self) -> u32 {
+        self.value ^ 0xffff_ffff
+    }
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65_521;
+
+    let (mut a, mut b) = (1, 0);
+    for &byte in data {
+        a = (a + u32::from(byte)) % MOD;
+        b = (b + a) % MOD;
+    }
+
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32_check_value() {
+        let mut crc = Crc32::new();
+        crc.update(b"123456789");
+
+        assert_eq!(crc.finish(), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn adler32_check_value() {
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn stored_blocks_split_large_data() {
+        let data = vec![7; MAX_STORED_BLOCK + 1];
+        let zlib = zlib_stored(&data);
+
+        // Header, two block headers, the data itself and the checksum.
+        assert_eq!(zlib.len(), 2 + 5 * 2 + data.len() + 4);
+        assert_eq!(zlib[2], 0);
+        assert_eq!(zlib[2 + 5 + MAX_STORED_BLOCK], 1);
+    }
+
+    #[test]
+    fn encodes_chunks() -> io::Result<()> {
+        let image = Indexed {
+            width: 2,
+            height: 1,
+            pixels: vec![0, 1],
+        };
+        let mut out = vec![];
+
+        encode(&mut out, &image, &[[0, 0, 0], [255, 255, 255]])?;
+
+        assert_eq!(out[..8], SIGNATURE);
+        assert_eq!(&out[12..16], b"IHDR");
+        // IEND always has the same length, type and checksum.
+        assert_eq!(
+            out[out.len() - 12..],
+            [0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]
+        );
+
+        Ok(())
+    }
+}
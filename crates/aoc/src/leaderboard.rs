@@ -0,0 +1,384 @@
+//! Analytics for a private leaderboard's JSON export: stars, local scores
+//! recomputed from when each star was earned, how long part 2 took after part
+//! 1, and how the rankings changed from day to day.
+
+use crate::table::{self, Format, Table};
+use anyhow::Context;
+use aoc_core::calendar;
+use serde::Deserialize;
+use std::{collections::BTreeMap, io};
+
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    /// When each star was earned, by day and then part.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+    /// The order stars were earned across the site, which breaks ties
+    /// between stars earned in the same second.
+    #[serde(default)]
+    pub star_index: u64,
+}
+
+/// A member's place on the leaderboard.
+#[derive(Debug)]
+pub struct Standing<'a> {
+    pub member: &'a Member,
+    pub stars: usize,
+    pub score: u64,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    pub fn stars(&self) -> usize {
+        self.completion_day_level
+            .values()
+            .map(|parts| parts.len())
+            .sum()
+    }
+
+    /// How many seconds after part 1 of a day the member solved part 2.
+    pub fn part_2_delay(&self, day: u8) -> Option<i64> {
+        let parts = self.completion_day_level.get(&day)?;
+
+        Some(parts.get(&2)?.get_star_ts - parts.get(&1)?.get_star_ts)
+    }
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> anyhow::Result<Self> {
+        serde_json::from_str(json).context("leaderboard is invalid")
+    }
+
+    pub fn year(&self) -> anyhow::Result<u16> {
+        self.event
+            .parse()
+            .with_context(|| format!("event is invalid: '{}'", self.event))
+    }
+
+    /// The last day anyone has a star for.
+    pub fn last_day(&self) -> Option<u8> {
+        self.members
+            .values()
+            .filter_map(|m| m.completion_day_level.keys().next_back())
+            .max()
+            .copied()
+    }
+
+    /// Every star as its earner, when it was earned, and the points it's worth:
+    /// the first member to earn a star gets a point per member, the next one
+    /// fewer, and so on.
+    fn points(&self) -> Vec<(u64, i64, u64)> {
+        let mut earners: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for member in self.members.values() {
+            for (&day, parts) in &member.completion_day_level {
+                for (&part, star) in parts {
+                    earners.entry((day, part)).or_default().push((
+                        star.get_star_ts,
+                        star.star_index,
+                        member.id,
+                    ));
+                }
+            }
+        }
+
+        let num_members = self.members.len() as u64;
+        let mut points = Vec::new();
+        for mut earners in earners.into_values() {
+            earners.sort_unstable();
+            for (rank, (ts, _, id)) in (0..).zip(earners) {
+                points.push((id, ts, num_members - rank));
+            }
+        }
+
+        points
+    }
+
+    /// Each member's local score from the stars earned before `until`.
+    pub fn scores_until(&self, until: i64) -> BTreeMap<u64, u64> {
+        let mut scores: BTreeMap<_, _> = self.members.values().map(|m| (m.id, 0)).collect();
+        for (id, ts, points) in self.points() {
+            if ts < until {
+                *scores.entry(id).or_default() += points;
+            }
+        }
+
+        scores
+    }
+
+    /// Each member's rank by the scores from stars earned before `until`,
+    /// where members on equal scores share a rank.
+    pub fn ranks_until(&self, until: i64) -> BTreeMap<u64, usize> {
+        let scores = self.scores_until(until);
+
+        scores
+            .iter()
+            .map(|(&id, score)| (id, 1 + scores.values().filter(|&s| s > score).count()))
+            .collect()
+    }
+
+    /// Members from highest score to lowest.
+    pub fn standings(&self) -> Vec<Standing<'_>> {
+        let scores = self.scores_until(i64::MAX);
+        let mut standings: Vec<_> = self
+            .members
+            .values()
+            .map(|member| Standing {
+                member,
+                stars: member.stars(),
+                score: scores[&member.id],
+            })
+            .collect();
+        standings.sort_by(|a, b| {
+            (b.score, b.stars)
+                .cmp(&(a.score, a.stars))
+                .then_with(|| a.member.display_name().cmp(&b.member.display_name()))
+        });
+
+        standings
+    }
+
+    /// When each day's standings are taken: as the next day unlocks, or now
+    /// for the last day.
+    fn checkpoint(&self, day: u8) -> anyhow::Result<i64> {
+        if Some(day) == self.last_day() || day == calendar::LAST_DAY {
+            Ok(i64::MAX)
+        } else {
+            calendar::unlocks_at(self.year()?, day + 1)
+        }
+    }
+
+    pub fn write_report(&self, w: &mut impl io::Write, format: Format) -> anyhow::Result<()> {
+        let standings = self.standings();
+        let days: Vec<u8> = (calendar::FIRST_DAY..=self.last_day().unwrap_or(0)).collect();
+        let active = standings.iter().filter(|s| s.stars > 0);
+
+        let mut totals = Table::new(["Member", "Stars", "Score", "Rank"]);
+        let ranks = self.ranks_until(i64::MAX);
+        for standing in &standings {
+            totals.push([
+                standing.member.display_name(),
+                standing.stars.to_string(),
+                standing.score.to_string(),
+                ranks[&standing.member.id].to_string(),
+            ]);
+        }
+
+        let header = || {
+            let days = days.iter().map(|day| day.to_string());
+            ["Member".to_string()].into_iter().chain(days)
+        };
+        let mut delays = Table::new(header());
+        for standing in active.clone() {
+            let member = standing.member;
+            let cells = days.iter().map(|&day| {
+                member
+                    .part_2_delay(day)
+                    .map_or_else(|| "-".to_string(), format_delay)
+            });
+            delays.push([member.display_name()].into_iter().chain(cells));
+        }
+
+        let mut rankings = Table::new(header());
+        let ranks = days
+            .iter()
+            .map(|&day| Ok(self.ranks_until(self.checkpoint(day)?)))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        for standing in active {
+            let id = standing.member.id;
+            let mut previous = None;
+            let cells = ranks.iter().map(|ranks| {
+                let rank = ranks[&id];
+                let cell = match previous {
+                    Some(previous) if previous > rank => format!("{} ↑{}", rank, previous - rank),
+                    Some(previous) if previous < rank => format!("{} ↓{}", rank, rank - previous),
+                    _ => rank.to_string(),
+                };
+                previous = Some(rank);
+                cell
+            });
+            let cells: Vec<_> = cells.collect();
+            rankings.push([standing.member.display_name()].into_iter().chain(cells));
+        }
+
+        for (i, (title, table)) in [
+            ("Standings", totals),
+            ("Part 2 delays", delays),
+            ("Rankings after each day", rankings),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            if i > 0 {
+                writeln!(w)?;
+            }
+            table::write_heading(w, format, title)?;
+            table.write(w, format)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A delay as hours, minutes and seconds, however many hours it is.
+fn format_delay(secs: i64) -> String {
+    format!("{}:{:02}:{:02}", secs / 3600, secs % 3600 / 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Three members over two days of 2021. Day 1 unlocked at 1638334800,
+    /// day 2 at 1638421200 and day 3 at 1638507600.
+    const EXPORT: &str = r#"{
+        "event": "2021",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "Ada", "stars": 3, "local_score": 6,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1638334900, "star_index": 10},
+                        "2": {"get_star_ts": 1638335200, "star_index": 40}
+                    },
+                    "2": {"1": {"get_star_ts": 1638421700, "star_index": 70}}
+                }
+            },
+            "2": {
+                "id": 2, "name": "Grace", "stars": 4, "local_score": 10,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1638335000, "star_index": 20},
+                        "2": {"get_star_ts": 1638335100, "star_index": 30}
+                    },
+                    "2": {
+                        "1": {"get_star_ts": 1638421300, "star_index": 50},
+                        "2": {"get_star_ts": 1638421400, "star_index": 60}
+                    }
+                }
+            },
+            "3": {
+                "id": 3, "name": null, "stars": 2, "local_score": 4,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1638334850, "star_index": 5},
+                        "2": {"get_star_ts": 1638507610, "star_index": 80}
+                    }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn recomputes_local_scores() -> anyhow::Result<()> {
+        let leaderboard = Leaderboard::parse(EXPORT)?;
+        let standings: Vec<_> = leaderboard
+            .standings()
+            .iter()
+            .map(|s| (s.member.display_name(), s.stars, s.score))
+            .collect();
+
+        assert_eq!(
+            standings,
+            [
+                ("Grace".to_string(), 4, 10),
+                ("Ada".to_string(), 3, 6),
+                ("(anonymous user #3)".to_string(), 2, 4),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn ranks_by_the_stars_earned_so_far() -> anyhow::Result<()> {
+        let leaderboard = Leaderboard::parse(EXPORT)?;
+
+        // Ada and Grace tie on day 1, before Grace pulls ahead on day 2.
+        let day_1 = leaderboard.ranks_until(leaderboard.checkpoint(1)?);
+        assert_eq!(
+            day_1.into_iter().collect::<Vec<_>>(),
+            [(1, 1), (2, 1), (3, 3)]
+        );
+        let day_2 = leaderboard.ranks_until(leaderboard.checkpoint(2)?);
+        assert_eq!(
+            day_2.into_iter().collect::<Vec<_>>(),
+            [(1, 2), (2, 1), (3, 3)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn measures_part_2_delays() -> anyhow::Result<()> {
+        let leaderboard = Leaderboard::parse(EXPORT)?;
+        let member = |id: &str| &leaderboard.members[id];
+
+        assert_eq!(member("1").part_2_delay(1), Some(300));
+        assert_eq!(member("1").part_2_delay(2), None);
+        assert_eq!(member("3").part_2_delay(1), Some(172_760));
+        assert_eq!(format_delay(172_760), "47:59:20");
+
+        Ok(())
+    }
+
+    #[test]
+    fn writes_reports() -> anyhow::Result<()> {
+        let leaderboard = Leaderboard::parse(EXPORT)?;
+        let mut out = Vec::new();
+        leaderboard.write_report(&mut out, Format::Markdown)?;
+
+        assert_eq!(
+            String::from_utf8(out)?,
+            "## Standings\n\
+             \n\
+             | Member              | Stars | Score | Rank |\n\
+             | ------------------- | ----: | ----: | ---: |\n\
+             | Grace               |     4 |    10 |    1 |\n\
+             | Ada                 |     3 |     6 |    2 |\n\
+             | (anonymous user #3) |     2 |     4 |    3 |\n\
+             \n\
+             ## Part 2 delays\n\
+             \n\
+             | Member              |        1 |       2 |\n\
+             | ------------------- | -------: | ------: |\n\
+             | Grace               |  0:01:40 | 0:01:40 |\n\
+             | Ada                 |  0:05:00 |       - |\n\
+             | (anonymous user #3) | 47:59:20 |       - |\n\
+             \n\
+             ## Rankings after each day\n\
+             \n\
+             | Member              |   1 |    2 |\n\
+             | ------------------- | --: | ---: |\n\
+             | Grace               |   1 |    1 |\n\
+             | Ada                 |   1 | 2 ↓1 |\n\
+             | (anonymous user #3) |   3 |    3 |\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_exports() {
+        assert!(Leaderboard::parse("{}").is_err());
+        assert!(Leaderboard::parse(r#"{"event": "2021", "members": {"1": {}}}"#).is_err());
+    }
+}
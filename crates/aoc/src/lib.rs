@@ -0,0 +1,12 @@
+pub mod allocations;
+pub mod budget;
+pub mod config;
+pub mod export;
+pub mod leaderboard;
+pub mod oracle;
+pub mod puzzle;
+pub mod site;
+pub mod table;
+pub mod trace;
+pub mod tui;
+pub mod years;
@@ -0,0 +1,792 @@
+use anyhow::{anyhow, bail, Context};
+use aoc::{
+    budget::{self, Check, Scope},
+    config::{self, Config},
+    export::{self, Palette},
+    leaderboard::Leaderboard,
+    oracle,
+    puzzle::{self, Puzzle},
+    site::{self, Site},
+    table::Format,
+    trace::{self, TraceSpec},
+    tui,
+    years::find_year,
+};
+use aoc_core::{
+    calendar::{self, Clock, FixedClock, SystemClock},
+    differential,
+    generate::{Rng, Size},
+    input, plugin, runner,
+    solutions::{InputKind, Year},
+    visualise,
+};
+use clap::{Args, Parser, Subcommand};
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    /// Which year's puzzles to use, defaulting to the latest
+    #[arg(long, global = true)]
+    year: Option<u16>,
+    /// Read puzzle inputs from this profile's directory, instead of the
+    /// configured profile's
+    #[arg(long, global = true, value_name = "NAME")]
+    profile: Option<String>,
+    /// A file holding the site's session cookie, instead of the configured one
+    #[arg(long, global = true, value_name = "PATH")]
+    session_file: Option<PathBuf>,
+    /// How many seconds to wait for the site or an oracle, instead of the
+    /// configured number
+    #[arg(long, global = true, value_name = "SECONDS")]
+    timeout: Option<u64>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run solvers and print their answers
+    Run {
+        /// Only run this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Only run this part (requires --day)
+        #[arg(long, requires = "day")]
+        part: Option<u8>,
+        /// Use the example input instead of the puzzle input
+        #[arg(long)]
+        example: bool,
+        /// Print a Markdown explanation of how each answer was derived
+        #[arg(long)]
+        explain: bool,
+        /// Emit a day's solver events, optionally at a level, e.g. `4` or `8=debug`
+        #[arg(long, value_name = "DAY[=LEVEL]")]
+        trace: Vec<TraceSpec>,
+        /// Write events to this file as JSON lines instead of stderr
+        #[arg(long, value_name = "PATH")]
+        trace_file: Option<PathBuf>,
+        /// Read the input from this file, or `-` for stdin (requires --day)
+        #[arg(value_name = "PATH", requires = "day", conflicts_with = "example")]
+        input: Option<PathBuf>,
+    },
+    /// Animate a part's visualisation in the terminal
+    Visualise {
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: u8,
+        /// Use the example input instead of the puzzle input
+        #[arg(long)]
+        example: bool,
+        /// Frames per second
+        #[arg(long, default_value_t = 10)]
+        fps: u32,
+    },
+    /// Export a part's visualisation as a PNG still or an animated GIF
+    Export {
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: u8,
+        /// Use the example input instead of the puzzle input
+        #[arg(long)]
+        example: bool,
+        /// Where to write the image; its extension picks the format
+        #[arg(long, value_name = "PATH")]
+        output: PathBuf,
+        /// Which frame to export as a PNG, defaulting to the last
+        #[arg(long)]
+        frame: Option<usize>,
+        /// Image pixels per glyph pixel
+        #[arg(long, default_value_t = 2)]
+        scale: usize,
+        /// Delay between GIF frames, in hundredths of a second
+        #[arg(long, default_value_t = 10)]
+        delay: u16,
+        /// Either `dark` or `light`
+        #[arg(long, default_value = "dark")]
+        palette: Palette,
+    },
+    /// Print a randomly generated input for a day
+    Generate {
+        #[arg(long)]
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Number of lines or values, defaulting to the size of a puzzle input
+        #[arg(long)]
+        count: Option<usize>,
+        /// A day-specific bound: day 3's bit width or day 7's furthest position
+        #[arg(long)]
+        width: Option<usize>,
+    },
+    /// Check optimised solvers against their reference implementations
+    Diff {
+        /// Only compare this day's implementations
+        #[arg(long)]
+        day: Option<u8>,
+        /// The seed of the first generated input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How many inputs to generate for each pair
+        #[arg(long, default_value_t = 100)]
+        cases: u64,
+        /// Where to save any input the implementations disagree on
+        #[arg(long, value_name = "DIR", default_value = "divergences")]
+        save_dir: PathBuf,
+    },
+    /// Cross-check answers with the commands configured under [oracles]
+    Oracle {
+        /// Only check this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Only check this part (requires --day)
+        #[arg(long, requires = "day")]
+        part: Option<u8>,
+        /// The seed of the first generated input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How many inputs to generate for each part, on top of the example
+        /// and puzzle inputs
+        #[arg(long, default_value_t = 0)]
+        cases: u64,
+        /// Where to save any input the answers disagree on
+        #[arg(long, value_name = "DIR", default_value = "disagreements")]
+        save_dir: PathBuf,
+    },
+    /// Check inputs against each day's constraints and summarise them
+    Inspect {
+        /// Only inspect this day's input
+        #[arg(long)]
+        day: Option<u8>,
+        /// Use the example input instead of the puzzle input
+        #[arg(long)]
+        example: bool,
+        /// Inspect this file instead, or `-` for stdin (requires --day)
+        #[arg(long, value_name = "PATH", requires = "day")]
+        input: Option<PathBuf>,
+    },
+    /// Run, benchmark and cross-check plugin solvers against the built-in ones
+    Plugins {
+        /// Only run this day's plugins
+        #[arg(long)]
+        day: Option<u8>,
+        /// Only run this part's plugins (requires --day)
+        #[arg(long, requires = "day")]
+        part: Option<u8>,
+        /// Use the example input instead of the puzzle input
+        #[arg(long)]
+        example: bool,
+        /// Read the input from this file, or `-` for stdin (requires --day)
+        #[arg(value_name = "PATH", requires = "day", conflicts_with = "example")]
+        input: Option<PathBuf>,
+        /// How many times to run each solver, instead of the configured
+        /// number; the median time is reported
+        #[arg(long)]
+        iterations: Option<usize>,
+        /// Where to load plugins from
+        #[arg(long, value_name = "DIR", default_value = "plugins")]
+        dir: PathBuf,
+        /// Only list the plugins' entries
+        #[arg(long)]
+        list: bool,
+    },
+    /// List every solved day and part
+    List,
+    /// Time every solver and fail if any goes over its budget in aoc.toml
+    Check {
+        /// Only check this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// How many times to run each solver, instead of the configured
+        /// number; the median time is compared with the budget
+        #[arg(long)]
+        iterations: Option<usize>,
+        /// The budget for parts without one of their own, e.g. `100ms`
+        #[arg(long, value_name = "TIME", value_parser = budget::parse_duration)]
+        budget: Option<Duration>,
+        /// Print an aligned `table` or `markdown`, instead of the configured format
+        #[arg(long)]
+        format: Option<Format>,
+    },
+    /// Report stars, scores and rankings for a private leaderboard
+    Leaderboard {
+        /// Read a saved JSON export of the leaderboard
+        #[arg(
+            value_name = "PATH",
+            required_unless_present = "id",
+            conflicts_with = "id"
+        )]
+        path: Option<PathBuf>,
+        /// Fetch the year's private leaderboard with this id instead
+        #[arg(long)]
+        id: Option<u64>,
+        /// Also save the fetched export here (requires --id)
+        #[arg(long, value_name = "PATH", requires = "id")]
+        save: Option<PathBuf>,
+        #[command(flatten)]
+        site: SiteArgs,
+        /// Print aligned `table`s or `markdown`, instead of the configured format
+        #[arg(long)]
+        format: Option<Format>,
+    },
+    /// Render a puzzle's description, and save its example input and answers
+    Puzzle {
+        #[arg(long)]
+        day: u8,
+        /// Read a saved copy of the puzzle's page instead of fetching it
+        #[arg(value_name = "PATH")]
+        page: Option<PathBuf>,
+        /// Which of the page's code blocks is the example input
+        #[arg(long, value_name = "N", default_value_t = 1)]
+        example: usize,
+        /// Where each day's resources go, instead of the configured directory
+        #[arg(long, value_name = "DIR")]
+        resources: Option<PathBuf>,
+        /// Save the example and its answers without asking
+        #[arg(long)]
+        yes: bool,
+        #[command(flatten)]
+        site: SiteArgs,
+    },
+    /// Show which days have unlocked and how long until the next one
+    Countdown {
+        /// Pretend it's this UTC time, e.g. `2021-12-24T12:00:00Z`
+        #[arg(long, value_name = "TIME")]
+        now: Option<String>,
+    },
+    /// Inspect the settings from aoc.toml and the environment
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+    /// Open the interactive dashboard
+    Tui {
+        /// Start with the example input selected
+        #[arg(long)]
+        example: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    /// Print the effective settings, merged from every source
+    Show,
+}
+
+#[derive(Args)]
+struct SiteArgs {
+    /// Where to fetch from
+    #[arg(long, env = "AOC_BASE_URL", default_value = site::DEFAULT_BASE_URL)]
+    base_url: String,
+    /// The site's session cookie, needed to fetch unless the configured
+    /// session file holds it
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+}
+
+impl SiteArgs {
+    fn site(&self, config: &Config) -> anyhow::Result<Site> {
+        let session = match &self.session {
+            Some(session) => session.clone(),
+            None => config.session()?.ok_or_else(|| {
+                anyhow!("fetching needs a session cookie in AOC_SESSION or session_file")
+            })?,
+        };
+
+        Ok(Site::new(&self.base_url, &session).with_timeout(Duration::from_secs(config.timeout)))
+    }
+}
+
+/// Asks a yes or no question on the terminal, taking anything but yes as no.
+fn confirm(question: &str) -> anyhow::Result<bool> {
+    print!("{} [y/N] ", question);
+    io::stdout().flush()?;
+    let mut reply = String::new();
+    io::stdin().read_line(&mut reply)?;
+
+    Ok(matches!(reply.trim(), "y" | "Y" | "yes"))
+}
+
+fn input_kind(example: bool) -> InputKind {
+    if example {
+        InputKind::Example
+    } else {
+        InputKind::Puzzle
+    }
+}
+
+fn frames(
+    year: &Year,
+    config: &Config,
+    day: u8,
+    part: u8,
+    example: bool,
+) -> anyhow::Result<Vec<visualise::Frame>> {
+    let day = year.day(day)?;
+    let part = day.part(part)?;
+    let visualise = part.visualise.ok_or_else(|| {
+        anyhow!(
+            "day {} part {} has no visualisation",
+            day.number,
+            part.number
+        )
+    })?;
+
+    visualise(&config.input(day, input_kind(example))?)
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let year = find_year(cli.year)?;
+    let (mut config, config_files) = Config::load()?;
+    if let Some(profile) = cli.profile {
+        config.profile = Some(profile);
+    }
+    if let Some(session_file) = cli.session_file {
+        config.session_file = Some(session_file);
+    }
+    if let Some(timeout) = cli.timeout {
+        config.timeout = timeout;
+    }
+    let mut stdout = io::stdout();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            example,
+            explain,
+            trace,
+            trace_file,
+            input: path,
+        } => {
+            trace::init(year.number, &trace, trace_file.as_deref())?;
+            let kind = input_kind(example);
+            let selected = year.select(day, part)?;
+
+            // Stdin can only be read once, so it's kept in memory when several
+            // parts need it. Explanations always need the whole input.
+            let text = match &path {
+                Some(path) if explain || (path == Path::new("-") && selected.len() > 1) => {
+                    Some(input::read_to_string(path)?)
+                }
+                _ => None,
+            };
+
+            for (i, (day, part)) in selected.into_iter().enumerate() {
+                // A profile stands in for the puzzle inputs the crates embed.
+                let profile_path = match &path {
+                    Some(_) => None,
+                    None => config.input_path(day, kind)?,
+                };
+                let path = path.as_deref().or(profile_path.as_deref());
+
+                if explain {
+                    if i > 0 {
+                        println!();
+                    }
+                    let read = match (&text, path) {
+                        (None, Some(path)) => Some(input::read_to_string(path)?),
+                        _ => None,
+                    };
+                    let input = text
+                        .as_deref()
+                        .or(read.as_deref())
+                        .unwrap_or(day.input(kind));
+                    runner::print_explanation(&mut stdout, day, part, input)?;
+                } else {
+                    let outcome = match (&text, path) {
+                        (Some(text), _) => runner::run(part, text),
+                        (None, Some(path)) => runner::run_file(part, path),
+                        (None, None) => runner::run(part, day.input(kind)),
+                    };
+                    runner::print_outcome(&mut stdout, day, part, &outcome)?;
+                }
+            }
+        }
+        Command::Visualise {
+            day,
+            part,
+            example,
+            fps,
+        } => {
+            let frames = frames(year, &config, day, part, example)?;
+            visualise::play(&mut stdout, &frames, fps, || Ok(false))?;
+        }
+        Command::Export {
+            day,
+            part,
+            example,
+            output,
+            frame,
+            scale,
+            delay,
+            palette,
+        } => {
+            let frames = frames(year, &config, day, part, example)?;
+            let options = export::Options {
+                palette,
+                scale,
+                delay,
+            };
+
+            match output.extension().and_then(|e| e.to_str()) {
+                Some("png") => {
+                    let idx = frame.unwrap_or(frames.len().saturating_sub(1));
+                    let frame = frames
+                        .get(idx)
+                        .ok_or_else(|| anyhow!("there is no frame {}", idx))?;
+                    export::write_png(&output, frame, options)?;
+                }
+                Some("gif") => export::write_gif(&output, &frames, options)?,
+                _ => bail!("output must end in .png or .gif"),
+            }
+        }
+        Command::Generate {
+            day,
+            seed,
+            count,
+            width,
+        } => {
+            let generator = year.generator(day)?;
+            let size = Size {
+                count: count.unwrap_or(generator.default_size.count),
+                width: width.unwrap_or(generator.default_size.width),
+            };
+
+            println!("{}", (generator.generate)(&mut Rng::new(seed), size));
+        }
+        Command::Diff {
+            day,
+            seed,
+            cases,
+            save_dir,
+        } => {
+            let mut diverged = false;
+
+            for pair in differential::select(year, day)? {
+                print!("Day {:02} / {}: ", pair.day, pair.name);
+                match differential::compare(year.generator(pair.day)?, pair, seed, cases)? {
+                    Some(divergence) => {
+                        let path = differential::save(&save_dir, pair, &divergence)?;
+                        println!("{}; input saved to {}", divergence, path.display());
+                        diverged = true;
+                    }
+                    None => println!("{} inputs agree", cases),
+                }
+            }
+
+            if diverged {
+                bail!("implementations diverged");
+            }
+        }
+        Command::Oracle {
+            day,
+            part,
+            seed,
+            cases,
+            save_dir,
+        } => {
+            let timeout = Duration::from_secs(config.timeout);
+            let mut checked = false;
+            let mut disagreed = false;
+            let mut failed = false;
+
+            for (day, part) in year.select(day, part)? {
+                let Some(command) = config.oracles.get(day.number, part.number) else {
+                    continue;
+                };
+                let generator = match cases {
+                    0 => None,
+                    _ => Some(year.generator(day.number)?),
+                };
+                let inputs = oracle::known(&config, day)?.into_iter().chain(
+                    generator
+                        .into_iter()
+                        .flat_map(|generator| oracle::generated(generator, seed, cases)),
+                );
+                checked = true;
+
+                print!("Day {:02} / Part {}: ", day.number, part.number);
+                match oracle::check(day, part, &command, timeout, inputs) {
+                    Ok((_, Some(disagreement))) => {
+                        let path = oracle::save(&save_dir, day, part, &disagreement)?;
+                        println!("{}; input saved to {}", disagreement, path.display());
+                        disagreed = true;
+                    }
+                    Ok((agreed, None)) => println!("{} inputs agree", agreed),
+                    Err(e) => {
+                        println!("error: {:#}", e);
+                        failed = true;
+                    }
+                }
+            }
+
+            if !checked {
+                bail!("no oracle answers the selected parts; add one under [oracles] in aoc.toml");
+            }
+            if disagreed {
+                bail!("answers disagreed with the oracle");
+            }
+            if failed {
+                bail!("the oracle failed");
+            }
+        }
+        Command::Inspect {
+            day,
+            example,
+            input,
+        } => {
+            let days = match day {
+                Some(day) => vec![year.day(day)?],
+                None => year.days.iter().collect(),
+            };
+            let input = input.as_deref().map(input::read_to_string).transpose()?;
+            let mut valid = true;
+
+            for (i, day) in days.into_iter().enumerate() {
+                let input = match &input {
+                    Some(input) => input.into(),
+                    None => config.input(day, input_kind(example))?,
+                };
+                let report = (day.inspect)(&input);
+
+                if i > 0 {
+                    println!();
+                }
+                report.write(&mut stdout, day)?;
+                valid &= report.is_valid();
+            }
+
+            if !valid {
+                bail!("inputs break their puzzle's constraints");
+            }
+        }
+        Command::Plugins {
+            day,
+            part,
+            example,
+            input: path,
+            iterations,
+            dir,
+            list,
+        } => {
+            let iterations = iterations.unwrap_or(config.iterations);
+            let solvers = plugin::load_dir(&dir)?;
+
+            if list {
+                for solver in &solvers {
+                    println!(
+                        "{} Day {:02} / Part {}: {} ({})",
+                        solver.year,
+                        solver.day,
+                        solver.part,
+                        solver.name,
+                        solver.source.display()
+                    );
+                }
+                return Ok(());
+            }
+
+            let text = path.as_deref().map(input::read_to_string).transpose()?;
+            let mut disagreed = false;
+
+            for (day, part) in year.select(day, part)? {
+                let plugins: Vec<_> = solvers
+                    .iter()
+                    .filter(|s| (s.year, s.day, s.part) == (year.number, day.number, part.number))
+                    .collect();
+                if plugins.is_empty() {
+                    continue;
+                }
+
+                let input = match &text {
+                    Some(text) => text.into(),
+                    None => config.input(day, input_kind(example))?,
+                };
+                let input = &*input;
+                let built_in = runner::bench(iterations, || (part.solve)(input));
+                runner::print_outcome(&mut stdout, day, part, &built_in)?;
+
+                for plugin in plugins {
+                    let outcome = runner::bench(iterations, || plugin.solve(input));
+                    print!("  {}: ", plugin.name);
+                    match (&built_in.answer, &outcome.answer) {
+                        (_, Err(e)) => {
+                            println!("error: {:#}", e);
+                            disagreed = true;
+                        }
+                        (Ok(expected), Ok(answer)) if expected == answer => {
+                            let speedup =
+                                built_in.elapsed.as_secs_f64() / outcome.elapsed.as_secs_f64();
+                            println!(
+                                "{} ({:.2?}), agrees, {:.1}x {}",
+                                answer,
+                                outcome.elapsed,
+                                speedup.max(1.0 / speedup),
+                                if speedup >= 1.0 { "faster" } else { "slower" }
+                            );
+                        }
+                        (_, Ok(answer)) => {
+                            println!("{} ({:.2?}), disagrees", answer, outcome.elapsed);
+                            disagreed = true;
+                        }
+                    }
+                }
+            }
+
+            if disagreed {
+                bail!("plugins disagree with the built-in solvers");
+            }
+        }
+        Command::Leaderboard {
+            path,
+            id,
+            save,
+            site,
+            format,
+        } => {
+            let json = match (path, id) {
+                (Some(path), _) => input::read_to_string(&path)?,
+                (None, Some(id)) => {
+                    let json = site.site(&config)?.leaderboard(year.number, id)?;
+                    if let Some(save) = save {
+                        fs::write(&save, &json)
+                            .with_context(|| format!("failed to write {}", save.display()))?;
+                    }
+                    json
+                }
+                (None, None) => unreachable!("clap requires a path or an id"),
+            };
+
+            Leaderboard::parse(&json)?
+                .write_report(&mut stdout, format.unwrap_or(config.format))?;
+        }
+        Command::Puzzle {
+            day,
+            page,
+            example,
+            resources,
+            yes,
+            site,
+        } => {
+            let html = match page {
+                Some(page) => input::read_to_string(&page)?,
+                None => site.site(&config)?.puzzle(&SystemClock, year.number, day)?,
+            };
+            let puzzle = Puzzle::parse(&html);
+            print!("{}", puzzle.markdown);
+
+            let Some(input) = example.checked_sub(1).and_then(|i| puzzle.examples.get(i)) else {
+                println!(
+                    "\nThere's no code block {} to use as the example input",
+                    example
+                );
+                return Ok(());
+            };
+            let answers = puzzle.example_answers();
+            let dir = match resources {
+                Some(resources) => resources.join(format!("day_{:02}", day)),
+                None => config.day_resources(year.number, day),
+            };
+
+            println!(
+                "\nExample input: code block {} of {}, {} lines",
+                example,
+                puzzle.examples.len(),
+                input.lines().count()
+            );
+            for (part, answer) in &answers {
+                println!("Example answer for part {}: {}", part, answer);
+            }
+
+            if yes || confirm(&format!("Save them to {}?", dir.display()))? {
+                puzzle::write_resources(&dir, input, &answers)?;
+                println!("Saved to {}", dir.display());
+            }
+        }
+        Command::Countdown { now } => {
+            let clock: Box<dyn Clock> = match now {
+                Some(now) => Box::new(FixedClock(calendar::parse_utc(&now)?)),
+                None => Box::new(SystemClock),
+            };
+            let next = calendar::next_unlock(&*clock)?;
+
+            match next.day - calendar::FIRST_DAY {
+                0 => {}
+                1 => println!("Day 1 of {} has unlocked", next.year),
+                unlocked => println!("Days 1-{} of {} have unlocked", unlocked, next.year),
+            }
+            println!(
+                "Day {} of {} unlocks in {}, at {}",
+                next.day,
+                next.year,
+                calendar::format_duration(next.at - clock.now()),
+                calendar::format_utc(next.at)
+            );
+        }
+        Command::Check {
+            day,
+            iterations,
+            budget,
+            format,
+        } => {
+            if cfg!(debug_assertions) {
+                eprintln!("Budgets are meant for release builds; debug builds run far slower");
+            }
+
+            let mut budgets = config.budgets.clone();
+            if let Some(budget) = budget {
+                budgets.set(Scope::Default, budget);
+            }
+            let iterations = iterations.unwrap_or(config.iterations);
+
+            let checks = year
+                .select(day, None)?
+                .into_iter()
+                .map(|(day, part)| {
+                    let input = config.input(day, InputKind::Puzzle)?;
+                    Ok(Check {
+                        day: day.number,
+                        part: part.number,
+                        outcome: runner::bench(iterations, || (part.solve)(&input)),
+                        budget: budgets.get(day.number, part.number),
+                    })
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            budget::report(&checks).write(&mut stdout, format.unwrap_or(config.format))?;
+            budget::ensure_within(&checks)?;
+        }
+        Command::List => {
+            for day in year.days {
+                for part in day.parts {
+                    println!("Day {:02} / Part {}", day.number, part.number);
+                }
+            }
+        }
+        Command::Config {
+            command: ConfigCommand::Show,
+        } => {
+            match config_files.as_slice() {
+                [] => println!("# No {} found; these are the defaults", config::FILE_NAME),
+                files => {
+                    for file in files {
+                        println!("# Read {}", file.display());
+                    }
+                }
+            }
+            print!("{}", config.to_toml()?);
+        }
+        Command::Tui { example } => tui::run(year, &config, input_kind(example))?,
+    }
+
+    Ok(())
+}
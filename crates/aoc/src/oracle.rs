@@ -0,0 +1,463 @@
+//! Cross-checking our answers against another implementation's, such as a
+//! colleague's in another language, run as a command that reads an input on
+//! stdin and prints its answer.
+
+use crate::{budget::Scope, config::Config};
+use anyhow::{bail, Context};
+use aoc_core::{
+    answer::Answer,
+    differential::describe,
+    generate::{Generator, Rng, Size},
+    runner,
+    solutions::{Day, InputKind, Part},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    process::{Child, Command, ExitStatus, Stdio},
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The commands declared for each scope, written `default`, `7` or `7.2` as
+/// budgets are, where a part's own command beats its day's, and its day's
+/// beats the default.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(
+    try_from = "BTreeMap<String, String>",
+    into = "BTreeMap<String, String>"
+)]
+pub struct Oracles(BTreeMap<Scope, String>);
+
+impl TryFrom<BTreeMap<String, String>> for Oracles {
+    type Error = anyhow::Error;
+
+    fn try_from(oracles: BTreeMap<String, String>) -> Result<Self, Self::Error> {
+        oracles
+            .into_iter()
+            .map(|(scope, command)| Ok((scope.parse()?, command)))
+            .collect::<anyhow::Result<_>>()
+            .map(Self)
+    }
+}
+
+impl From<Oracles> for BTreeMap<String, String> {
+    fn from(oracles: Oracles) -> Self {
+        oracles
+            .0
+            .into_iter()
+            .map(|(scope, command)| (scope.to_string(), command))
+            .collect()
+    }
+}
+
+impl Oracles {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// These commands, with any that `over` declares taking precedence.
+    pub fn merge(mut self, over: Self) -> Self {
+        self.0.extend(over.0);
+        self
+    }
+
+    /// The command answering a part, with `{day}` and `{part}` filled in.
+    pub fn get(&self, day: u8, part: u8) -> Option<String> {
+        [Scope::Part(day, part), Scope::Day(day), Scope::Default]
+            .iter()
+            .find_map(|scope| self.0.get(scope))
+            .map(|command| {
+                command
+                    .replace("{day}", &day.to_string())
+                    .replace("{part}", &part.to_string())
+            })
+    }
+}
+
+fn shell(command: &str) -> Command {
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+    let mut shell = Command::new(shell);
+    shell.args([flag, command]);
+
+    shell
+}
+
+/// Reads all of a pipe on another thread, sending what it read once the
+/// pipe closes.
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> Receiver<Vec<u8>> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let mut read = vec![];
+        let _ = pipe.read_to_end(&mut read);
+        let _ = sender.send(read);
+    });
+
+    receiver
+}
+
+/// Waits for a child to exit, killing it if it hasn't by the deadline.
+fn wait_until(child: &mut Child, deadline: Instant) -> io::Result<Option<ExitStatus>> {
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+}
+
+/// Runs a command through the shell with the input on its stdin, and with
+/// `AOC_DAY` and `AOC_PART` set, and reads its answer from its stdout. Fails
+/// if it exits unsuccessfully, or if it hasn't answered within `timeout`, in
+/// which case it's killed.
+pub fn ask(
+    command: &str,
+    day: u8,
+    part: u8,
+    input: &str,
+    timeout: Duration,
+) -> anyhow::Result<Answer> {
+    let deadline = Instant::now() + timeout;
+    let mut child = shell(command)
+        .env("AOC_DAY", day.to_string())
+        .env("AOC_PART", part.to_string())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("failed to run '{}'", command))?;
+
+    // Written and read on other threads, so that a command answering before
+    // it's read all of its input can't leave both of us waiting on the other,
+    // and so that a command that never answers can still be killed. Anything
+    // it started may outlive it and hold the pipes open, so the threads aren't
+    // waited for.
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let input = input.to_string();
+    thread::spawn(move || {
+        // A command needn't read its input at all, so a closed pipe isn't an
+        // error; its exit status says whether it failed.
+        let _ = stdin.write_all(input.as_bytes());
+    });
+    let stdout = read_in_background(child.stdout.take().expect("stdout is piped"));
+    let stderr = read_in_background(child.stderr.take().expect("stderr is piped"));
+
+    let answer = stdout.recv_timeout(timeout).ok();
+    let status =
+        wait_until(&mut child, deadline).with_context(|| format!("failed to run '{}'", command))?;
+    let (Some(status), Some(answer)) = (status, answer) else {
+        bail!("'{}' didn't answer within {:?}", command, timeout);
+    };
+
+    if !status.success() {
+        let stderr = stderr
+            .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            .unwrap_or_default();
+        match String::from_utf8_lossy(&stderr).trim() {
+            "" => bail!("'{}' failed with {}", command, status),
+            stderr => bail!("'{}' failed with {}: {}", command, status, stderr),
+        }
+    }
+    let answer = String::from_utf8(answer)
+        .with_context(|| format!("'{}' didn't answer in UTF-8", command))?;
+
+    Ok(answer.parse().unwrap_or_else(|e| match e {}))
+}
+
+/// Where an input came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Source {
+    Known(InputKind),
+    Seed(u64),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Known(kind) => write!(f, "the {} input", kind),
+            Self::Seed(seed) => write!(f, "the input from seed {}", seed),
+        }
+    }
+}
+
+pub struct Case {
+    pub source: Source,
+    pub input: String,
+}
+
+/// A day's example and puzzle inputs, the latter from the selected profile
+/// if there is one.
+pub fn known(config: &Config, day: &Day) -> anyhow::Result<Vec<Case>> {
+    [InputKind::Example, InputKind::Puzzle]
+        .into_iter()
+        .map(|kind| {
+            Ok(Case {
+                source: Source::Known(kind),
+                input: config.input(day, kind)?.into_owned(),
+            })
+        })
+        .collect()
+}
+
+/// Inputs generated from consecutive seeds, each sized randomly up to the
+/// size of a puzzle input.
+pub fn generated(
+    generator: &Generator,
+    first_seed: u64,
+    count: u64,
+) -> impl Iterator<Item = Case> + '_ {
+    (first_seed..first_seed.saturating_add(count)).map(|seed| {
+        let mut rng = Rng::new(seed);
+        let size = Size {
+            count: 1 + rng.below(generator.default_size.count as u64) as usize,
+            ..generator.default_size
+        };
+
+        Case {
+            source: Source::Seed(seed),
+            input: (generator.generate)(&mut rng, size),
+        }
+    })
+}
+
+/// The first input on which our answer and the oracle's differ.
+#[derive(Debug)]
+pub struct Disagreement {
+    pub source: Source,
+    pub input: String,
+    pub ours: String,
+    pub theirs: String,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "disagreed on {}: we answered {}, the oracle answered {}",
+            self.source, self.ours, self.theirs
+        )
+    }
+}
+
+/// Solves each input and asks the oracle, stopping at the first input they
+/// disagree on, and returning how many agreed before it. An oracle that fails
+/// to answer is an error, whatever our solver made of the input.
+pub fn check(
+    day: &Day,
+    part: &Part,
+    command: &str,
+    timeout: Duration,
+    cases: impl IntoIterator<Item = Case>,
+) -> anyhow::Result<(u64, Option<Disagreement>)> {
+    let mut agreed = 0;
+    for case in cases {
+        let theirs = ask(command, day.number, part.number, &case.input, timeout)
+            .with_context(|| format!("the oracle failed on {}", case.source))?;
+        let ours = runner::solve(part.solve, &case.input);
+
+        if !ours.as_ref().is_ok_and(|ours| *ours == theirs) {
+            return Ok((
+                agreed,
+                Some(Disagreement {
+                    source: case.source,
+                    input: case.input,
+                    ours: describe(&ours),
+                    theirs: theirs.to_string(),
+                }),
+            ));
+        }
+        agreed += 1;
+    }
+
+    Ok((agreed, None))
+}
+
+/// Saves an input we disagreed on as `day_NN_part_N_<source>.txt` in `dir`.
+pub fn save(
+    dir: &Path,
+    day: &Day,
+    part: &Part,
+    disagreement: &Disagreement,
+) -> anyhow::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+
+    let source = match disagreement.source {
+        Source::Known(kind) => kind.to_string(),
+        Source::Seed(seed) => format!("seed_{}", seed),
+    };
+    let path = dir.join(format!(
+        "day_{:02}_part_{}_{}.txt",
+        day.number, part.number, source
+    ));
+    fs::write(&path, &disagreement.input)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: Day = Day {
+        number: 1,
+        example_input: "1\n2\n3\n",
+        puzzle_input: "1\n2\n3\n4\n5\n6\n7\n",
+        answers: "",
+        parse: |input| Ok(input.to_string()),
+        inspect: |_| aoc_core::inspect::Report::new(),
+        parts: &[
+            Part {
+                number: 1,
+                solve: |input| Ok(input.lines().count().min(5).into()),
+                stream: None,
+                bytes: None,
+                explain: None,
+                visualise: None,
+            },
+            Part {
+                number: 2,
+                solve: |_| anyhow::bail!("no answer"),
+                stream: None,
+                bytes: None,
+                explain: None,
+                visualise: None,
+            },
+        ],
+    };
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn config() -> Config {
+        Config::from(crate::config::Layer::default())
+    }
+
+    fn oracles(declared: &[(&str, &str)]) -> anyhow::Result<Oracles> {
+        Oracles::try_from(
+            declared
+                .iter()
+                .map(|&(scope, command)| (scope.to_string(), command.to_string()))
+                .collect::<BTreeMap<_, _>>(),
+        )
+    }
+
+    #[test]
+    fn prefers_the_narrowest_command() -> anyhow::Result<()> {
+        let oracles = oracles(&[
+            ("default", "python3 day_{day}.py {part}"),
+            ("7", "./crabs"),
+            ("7.2", "./crabs --part 2"),
+        ])?;
+
+        assert_eq!(oracles.get(1, 2).as_deref(), Some("python3 day_1.py 2"));
+        assert_eq!(oracles.get(7, 1).as_deref(), Some("./crabs"));
+        assert_eq!(oracles.get(7, 2).as_deref(), Some("./crabs --part 2"));
+        assert_eq!(Oracles::default().get(7, 2), None);
+        assert!(Oracles::try_from(BTreeMap::from([(
+            "seven".to_string(),
+            "./crabs".to_string()
+        )]))
+        .is_err());
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn asks_with_the_input_on_stdin() -> anyhow::Result<()> {
+        assert_eq!(ask("wc -l", 1, 1, "1\n2\n3\n", TIMEOUT)?, 3);
+        assert_eq!(ask("echo $AOC_DAY.$AOC_PART", 7, 2, "", TIMEOUT)?, "7.2");
+        assert!(ask("echo nope >&2; exit 1", 1, 1, "", TIMEOUT)
+            .unwrap_err()
+            .to_string()
+            .ends_with("nope"));
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn kills_an_oracle_that_takes_too_long() {
+        let start = Instant::now();
+        let error = ask("sleep 10", 1, 1, "", Duration::from_millis(100)).unwrap_err();
+
+        assert!(error.to_string().contains("didn't answer within"));
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn a_failing_oracle_never_agrees() {
+        let part = &DAY.parts[1];
+
+        for command in ["exit 1", "./no-such-oracle"] {
+            let error = check(
+                &DAY,
+                part,
+                command,
+                TIMEOUT,
+                known(&config(), &DAY).unwrap(),
+            );
+
+            assert!(error
+                .unwrap_err()
+                .to_string()
+                .starts_with("the oracle failed on the example input"));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn reports_and_saves_the_first_disagreement() -> anyhow::Result<()> {
+        let part = &DAY.parts[0];
+
+        let (agreed, disagreement) = check(&DAY, part, "wc -l", TIMEOUT, known(&config(), &DAY)?)?;
+        let disagreement = disagreement.expect("the puzzle input must disagree");
+        assert_eq!(agreed, 1);
+        assert_eq!(disagreement.source, Source::Known(InputKind::Puzzle));
+        assert_eq!(
+            (disagreement.ours.as_str(), disagreement.theirs.as_str()),
+            ("5", "7")
+        );
+
+        let dir = std::env::temp_dir().join(format!("aoc-oracle-{}", std::process::id()));
+        let path = save(&dir, &DAY, part, &disagreement)?;
+        let saved = fs::read_to_string(&path)?;
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(saved, DAY.puzzle_input);
+        assert!(path.ends_with("day_01_part_1_puzzle.txt"));
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn checks_generated_inputs() {
+        let generator = Generator {
+            day: 1,
+            default_size: Size { count: 4, width: 0 },
+            generate: |rng, size| "1\n".repeat(rng.below(size.count as u64 + 1) as usize),
+        };
+        let part = &DAY.parts[0];
+
+        let (agreed, disagreement) =
+            check(&DAY, part, "wc -l", TIMEOUT, generated(&generator, 0, 20)).expect("wc answers");
+
+        assert_eq!(agreed, 20);
+        assert!(disagreement.is_none());
+    }
+}
@@ -0,0 +1,451 @@
+//! Puzzle pages: their descriptions as Markdown for the terminal, and the
+//! example inputs and answers they contain.
+
+use anyhow::Context;
+use aoc_core::{
+    answer::Answer,
+    solutions::{parse_answers, InputKind},
+};
+use std::{fs, path::Path};
+
+/// How wide paragraphs are wrapped.
+const WIDTH: usize = 80;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    /// The description as Markdown, one section per part.
+    pub markdown: String,
+    /// Each `<pre><code>` block, the first usually being the example input.
+    pub examples: Vec<String>,
+    /// Each part's emphasised `<code><em>` values, the last usually being the
+    /// example's answer.
+    pub answers: Vec<Vec<String>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Text(&'a str),
+    Open { name: &'a str, attrs: &'a str },
+    Close(&'a str),
+}
+
+/// Splits HTML into text and tags, dropping comments. Tags aren't matched up,
+/// which is all the site's pages need.
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.split_once("-->").map_or("", |(_, rest)| rest);
+        } else if let Some((tag, after)) = rest.strip_prefix('<').and_then(|r| r.split_once('>')) {
+            rest = after;
+            let tag = tag.trim_end_matches('/').trim();
+            tokens.push(match tag.strip_prefix('/') {
+                Some(name) => Token::Close(name.trim()),
+                None => {
+                    let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                    Token::Open { name, attrs }
+                }
+            });
+        } else {
+            // Text starts with at least one character, which may be a '<'
+            // that doesn't open a tag.
+            let end = rest
+                .char_indices()
+                .skip(1)
+                .find(|&(_, c)| c == '<')
+                .map_or(rest.len(), |(end, _)| end);
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+
+    tokens
+}
+
+/// An attribute's value, if it's quoted with double quotes.
+fn attr<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{}=\"", name))? + name.len() + 2;
+    let len = attrs[start..].find('"')?;
+
+    Some(&attrs[start..start + len])
+}
+
+/// Replaces character references with what they stand for, leaving any it
+/// doesn't know alone.
+fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let reference = rest[1..].find(';').map(|end| &rest[1..end + 1]);
+        let c = reference.and_then(|reference| match reference {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = reference.strip_prefix('#')?;
+                let code = match code.strip_prefix('x') {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => code.parse().ok()?,
+                };
+                char::from_u32(code)
+            }
+        });
+
+        match (c, reference) {
+            (Some(c), Some(reference)) => {
+                decoded.push(c);
+                rest = &rest[reference.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+
+    decoded
+}
+
+/// Wraps text at spaces to fit within `WIDTH`, indenting continued lines.
+fn wrap(text: &str, indent: &str) -> String {
+    let mut wrapped = String::new();
+    let mut line_len = 0;
+
+    for word in text.split(' ').filter(|w| !w.is_empty()) {
+        let word_len = word.chars().count();
+        if line_len > 0 && line_len + 1 + word_len > WIDTH {
+            wrapped.push('\n');
+            wrapped.push_str(indent);
+            line_len = indent.len();
+        } else if line_len > 0 {
+            wrapped.push(' ');
+            line_len += 1;
+        }
+        wrapped.push_str(word);
+        line_len += word_len;
+    }
+
+    wrapped
+}
+
+impl Puzzle {
+    /// Reads the `<article>`s of a puzzle page.
+    pub fn parse(html: &str) -> Self {
+        let mut puzzle = Self::default();
+        // Finished blocks, and whether each is a list item, which are kept
+        // together rather than separated by blank lines.
+        let mut blocks: Vec<(String, bool)> = Vec::new();
+        let mut block = String::new();
+        let mut pre: Option<String> = None;
+        let mut in_article = false;
+        let mut in_code = false;
+        let mut emphasis: Option<String> = None;
+        let mut hrefs = Vec::new();
+
+        for token in tokenize(html) {
+            match token {
+                Token::Open {
+                    name: "article", ..
+                } => {
+                    in_article = true;
+                    puzzle.answers.push(Vec::new());
+                }
+                Token::Close("article") => in_article = false,
+                _ if !in_article => {}
+
+                Token::Open { name: "pre", .. } => pre = Some(String::new()),
+                Token::Close("pre") => {
+                    if let Some(mut example) = pre.take() {
+                        if !example.ends_with('\n') {
+                            example.push('\n');
+                        }
+                        blocks.push((format!("```\n{}```", example), false));
+                        puzzle.examples.push(example);
+                    }
+                }
+                Token::Text(text) if pre.is_some() => {
+                    pre.get_or_insert_with(String::new).push_str(&decode(text));
+                }
+                _ if pre.is_some() => {}
+
+                Token::Open { name: "li", .. } => block.push_str("- "),
+                Token::Close(name @ ("h2" | "p" | "li")) => {
+                    let text = block.trim();
+                    let text = match name {
+                        "h2" => format!("## {}", text.trim_matches(|c| c == '-' || c == ' ')),
+                        "li" => wrap(text, "  "),
+                        _ => wrap(text, ""),
+                    };
+                    blocks.push((text, name == "li"));
+                    block.clear();
+                }
+
+                Token::Open { name: "code", .. } => {
+                    in_code = true;
+                    block.push('`');
+                }
+                Token::Close("code") => {
+                    in_code = false;
+                    block.push('`');
+                }
+                Token::Open { name: "em", .. } if in_code => emphasis = Some(String::new()),
+                Token::Close("em") if emphasis.is_some() => {
+                    if let (Some(answer), Some(answers)) =
+                        (emphasis.take(), puzzle.answers.last_mut())
+                    {
+                        answers.push(answer);
+                    }
+                }
+                Token::Open { name: "em", .. } | Token::Close("em") => block.push('*'),
+                Token::Open { name: "a", attrs } => {
+                    hrefs.push(attr(attrs, "href").unwrap_or_default());
+                    block.push('[');
+                }
+                Token::Close("a") => {
+                    block.push_str("](");
+                    block.push_str(hrefs.pop().unwrap_or_default());
+                    block.push(')');
+                }
+
+                Token::Text(text) => {
+                    let text = decode(text);
+                    if let Some(emphasis) = &mut emphasis {
+                        emphasis.push_str(&text);
+                    }
+                    for c in text.chars() {
+                        if !c.is_whitespace() {
+                            block.push(c);
+                        } else if !block.is_empty() && !block.ends_with(' ') {
+                            block.push(' ');
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for (i, (text, is_item)) in blocks.iter().enumerate() {
+            if i > 0 {
+                let in_list = *is_item && blocks[i - 1].1;
+                puzzle
+                    .markdown
+                    .push_str(if in_list { "\n" } else { "\n\n" });
+            }
+            puzzle.markdown.push_str(text);
+        }
+        if !puzzle.markdown.is_empty() {
+            puzzle.markdown.push('\n');
+        }
+
+        puzzle
+    }
+
+    /// The last emphasised value of each part, as its example answer.
+    pub fn example_answers(&self) -> Vec<(u8, Answer)> {
+        (1..)
+            .zip(&self.answers)
+            .filter_map(|(part, answers)| {
+                let answer = answers.last()?.parse().unwrap_or_else(|e| match e {});
+                Some((part, answer))
+            })
+            .collect()
+    }
+}
+
+/// Writes an example input and its answers to a day's resources directory,
+/// keeping the answers already there for other inputs and parts.
+pub fn write_resources(dir: &Path, example: &str, answers: &[(u8, Answer)]) -> anyhow::Result<()> {
+    fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+
+    let example_path = dir.join("example_input.txt");
+    fs::write(&example_path, example)
+        .with_context(|| format!("failed to write {}", example_path.display()))?;
+
+    let answers_path = dir.join("answers.txt");
+    let existing = match fs::read_to_string(&answers_path) {
+        Ok(text) => parse_answers(&text)
+            .with_context(|| format!("failed to read {}", answers_path.display()))?,
+        Err(_) => Vec::new(),
+    };
+    let mut merged: Vec<_> = existing
+        .into_iter()
+        .filter(|(kind, part, _)| {
+            *kind != InputKind::Example || !answers.iter().any(|(p, _)| p == part)
+        })
+        .chain(
+            answers
+                .iter()
+                .map(|(part, answer)| (InputKind::Example, *part, answer.clone())),
+        )
+        .collect();
+    merged.sort_by_key(|(kind, part, _)| (*kind == InputKind::Puzzle, *part));
+
+    let text: String = merged
+        .iter()
+        .map(|(kind, part, answer)| format!("{} {} {}\n", kind, part, answer))
+        .collect();
+    fs::write(&answers_path, text)
+        .with_context(|| format!("failed to write {}", answers_path.display()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Trimmed from 2021 day 1's page, once both parts were solved.
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 1 - Advent of Code 2021</title></head>
+<body>
+<header><h1 class="title-global"><a href="/">Advent of Code</a></h1></header>
+<main>
+<article class="day-desc"><h2>--- Day 1: Sonar Sweep ---</h2><p>As the submarine drops below the surface of the ocean, it automatically performs a sonar sweep of the nearby sea floor.</p>
+<p>For example, suppose you had the following report:</p>
+<pre><code>199
+200
+208
+</code></pre>
+<p>This report indicates that, scanning outward from the submarine, the sonar sweep found depths of <code>199</code> and <code>200</code>. The first order of business is to figure out how quickly the depth increases, just so you know what you're dealing with - you never know if the keys will get <a href="https://en.wikipedia.org/wiki/Buoyancy" target="_blank">carried into deeper water</a> by an ocean current.</p>
+<ul>
+<li><code>199</code> (<em>N/A - no previous measurement</em>)</li>
+<li><code>200</code> (<em>increased</em>)</li>
+</ul>
+<p>In this example, there are <code><em>7</em></code> measurements that are larger than the previous measurement.</p>
+<p><em>How many measurements are larger than the previous measurement?</em></p>
+</article>
+<p>Your puzzle answer was <code>1527</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Instead, consider sums of a <em>three-measurement sliding window</em> &amp; compare them:</p>
+<pre><code>199  A
+200  A B
+</code></pre>
+<p>In this example, there are <code><em>5</em></code> sums that are larger than the previous sum.</p>
+</article>
+</main>
+</body>
+</html>
+"#;
+
+    #[test]
+    fn renders_the_articles_as_markdown() {
+        let puzzle = Puzzle::parse(PAGE);
+
+        assert_eq!(
+            puzzle.markdown,
+            "## Day 1: Sonar Sweep\n\
+             \n\
+             As the submarine drops below the surface of the ocean, it automatically performs\n\
+             a sonar sweep of the nearby sea floor.\n\
+             \n\
+             For example, suppose you had the following report:\n\
+             \n\
+             ```\n\
+             199\n\
+             200\n\
+             208\n\
+             ```\n\
+             \n\
+             This report indicates that, scanning outward from the submarine, the sonar sweep\n\
+             found depths of `199` and `200`. The first order of business is to figure out\n\
+             how quickly the depth increases, just so you know what you're dealing with - you\n\
+             never know if the keys will get [carried into deeper\n\
+             water](https://en.wikipedia.org/wiki/Buoyancy) by an ocean current.\n\
+             \n\
+             - `199` (*N/A - no previous measurement*)\n\
+             - `200` (*increased*)\n\
+             \n\
+             In this example, there are `7` measurements that are larger than the previous\n\
+             measurement.\n\
+             \n\
+             *How many measurements are larger than the previous measurement?*\n\
+             \n\
+             ## Part Two\n\
+             \n\
+             Instead, consider sums of a *three-measurement sliding window* & compare them:\n\
+             \n\
+             ```\n\
+             199  A\n\
+             200  A B\n\
+             ```\n\
+             \n\
+             In this example, there are `5` sums that are larger than the previous sum.\n"
+        );
+    }
+
+    #[test]
+    fn extracts_examples_and_answers() {
+        let puzzle = Puzzle::parse(PAGE);
+
+        assert_eq!(puzzle.examples[0], "199\n200\n208\n");
+        assert_eq!(
+            puzzle.answers,
+            [vec!["7".to_string()], vec!["5".to_string()]]
+        );
+        assert_eq!(
+            puzzle.example_answers(),
+            [(1, Answer::Int(7)), (2, Answer::Int(5))]
+        );
+    }
+
+    #[test]
+    fn tokenizes_text_starting_with_any_character() {
+        assert_eq!(
+            tokenize("<p>é is here</p>< 3 ü"),
+            [
+                Token::Open {
+                    name: "p",
+                    attrs: ""
+                },
+                Token::Text("é is here"),
+                Token::Close("p"),
+                Token::Text("< 3 ü"),
+            ]
+        );
+        assert!(Puzzle::parse("<article><p>é is here</p></article>")
+            .markdown
+            .contains("é is here"));
+    }
+
+    #[test]
+    fn decodes_character_references() {
+        assert_eq!(
+            decode("a &lt;b&gt; &amp;&#39;c&#x27; &unknown; & d"),
+            "a <b> &'c' &unknown; & d"
+        );
+    }
+
+    #[test]
+    fn merges_answers_into_resources() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-puzzle-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join("answers.txt"), "example 1 6\npuzzle 1 1527\n")?;
+
+        write_resources(
+            &dir,
+            "199\n200\n",
+            &[(1, Answer::Int(7)), (2, Answer::Int(5))],
+        )?;
+
+        assert_eq!(
+            fs::read_to_string(dir.join("example_input.txt"))?,
+            "199\n200\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("answers.txt"))?,
+            "example 1 7\nexample 2 5\npuzzle 1 1527\n"
+        );
+        fs::remove_dir_all(&dir)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+//! Requests to the Advent of Code site, signed in with a session cookie.
+
+use anyhow::Context;
+use aoc_core::calendar::{self, Clock};
+use std::{io::Read, time::Duration};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The site, or anything serving the same paths, such as a stub in tests.
+pub struct Site {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Site {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::Agent::new(),
+        }
+    }
+
+    /// Gives up on requests that take longer than `timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.agent = ureq::AgentBuilder::new().timeout(timeout).build();
+        self
+    }
+
+    fn get(&self, path: &str) -> anyhow::Result<String> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .with_context(|| format!("failed to fetch {}", url))?;
+
+        let mut body = String::new();
+        response
+            .into_reader()
+            .read_to_string(&mut body)
+            .with_context(|| format!("failed to read {}", url))?;
+
+        Ok(body)
+    }
+
+    /// A day's puzzle page, refusing to ask for it before it unlocks.
+    pub fn puzzle(&self, clock: &dyn Clock, year: u16, day: u8) -> anyhow::Result<String> {
+        calendar::ensure_unlocked(clock, year, day)?;
+
+        self.get(&format!("/{}/day/{}", year, day))
+    }
+
+    /// A private leaderboard's JSON export.
+    pub fn leaderboard(&self, year: u16, id: u64) -> anyhow::Result<String> {
+        self.get(&format!("/{}/leaderboard/private/view/{}.json", year, id))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serves `body` to a single request, and returns the request's lines.
+    pub fn serve(status: &'static str, body: &'static str) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("stub must bind");
+        let base_url = format!(
+            "http://{}",
+            listener.local_addr().expect("stub has an address")
+        );
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("stub must accept");
+            let request: Vec<String> = BufReader::new(&stream)
+                .lines()
+                .map_while(Result::ok)
+                .take_while(|line| !line.is_empty())
+                .collect();
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .expect("stub must respond");
+
+            request
+        });
+
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fetches_leaderboards_with_the_session() -> anyhow::Result<()> {
+        let (base_url, stub) = stub::serve("200 OK", r#"{"members":{}}"#);
+        let site = Site::new(&format!("{}/", base_url), "abc123\n");
+
+        assert_eq!(site.leaderboard(2021, 42)?, r#"{"members":{}}"#);
+
+        let request = stub.join().expect("stub mustn't panic");
+        assert_eq!(
+            request[0],
+            "GET /2021/leaderboard/private/view/42.json HTTP/1.1"
+        );
+        assert!(request.iter().any(|line| line == "Cookie: session=abc123"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn refuses_to_fetch_locked_puzzles() -> anyhow::Result<()> {
+        let site = Site::new("http://127.0.0.1:9", "abc123");
+        let clock = calendar::FixedClock(calendar::parse_utc("2021-12-07T12:00:00Z")?);
+
+        let err = site.puzzle(&clock, 2021, 8).unwrap_err();
+        assert!(err.to_string().contains("still locked"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fetches_unlocked_puzzles() -> anyhow::Result<()> {
+        let (base_url, stub) = stub::serve("200 OK", "<article></article>");
+        let site = Site::new(&base_url, "abc123");
+        let clock = calendar::FixedClock(calendar::parse_utc("2021-12-08T05:00:00Z")?);
+
+        assert_eq!(site.puzzle(&clock, 2021, 8)?, "<article></article>");
+        assert_eq!(
+            stub.join().expect("stub mustn't panic")[0],
+            "GET /2021/day/8 HTTP/1.1"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_on_error_statuses() {
+        let (base_url, stub) = stub::serve("404 Not Found", "");
+        let site = Site::new(&base_url, "abc123");
+
+        assert!(site.leaderboard(2021, 42).is_err());
+        stub.join().expect("stub mustn't panic");
+    }
+}
@@ -0,0 +1,171 @@
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+use std::{fmt, io, str::FromStr};
+
+/// How reports are printed: aligned for the terminal, or as Markdown to
+/// paste elsewhere.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Table,
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Self::Table),
+            "markdown" => Ok(Self::Markdown),
+            _ => bail!("format is invalid: '{}'", s),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Table => "table",
+            Self::Markdown => "markdown",
+        })
+    }
+}
+
+/// Rows of cells under a header, with every column but the first aligned
+/// right.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Table {
+    pub header: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<S: Into<String>>(header: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            header: header.into_iter().map(Into::into).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push<S: Into<String>>(&mut self, row: impl IntoIterator<Item = S>) {
+        self.rows.push(row.into_iter().map(Into::into).collect());
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        let mut widths: Vec<_> = self.header.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        widths
+    }
+
+    pub fn write(&self, w: &mut impl io::Write, format: Format) -> io::Result<()> {
+        // Markdown needs at least three dashes under each header.
+        let widths: Vec<_> = match format {
+            Format::Table => self.widths(),
+            Format::Markdown => self.widths().into_iter().map(|w| w.max(3)).collect(),
+        };
+        let line = |w: &mut dyn io::Write, cells: &[String]| -> io::Result<()> {
+            let cells = widths.iter().enumerate().map(|(i, &width)| {
+                let cell = cells.get(i).map_or("", String::as_str);
+                if i == 0 {
+                    format!("{:<width$}", cell)
+                } else {
+                    format!("{:>width$}", cell)
+                }
+            });
+            let cells: Vec<_> = cells.collect();
+
+            match format {
+                Format::Table => writeln!(w, "{}", cells.join("  ").trim_end()),
+                Format::Markdown => writeln!(w, "| {} |", cells.join(" | ")),
+            }
+        };
+
+        line(w, &self.header)?;
+        match format {
+            Format::Table => {
+                let rule: Vec<_> = widths.iter().map(|&width| "-".repeat(width)).collect();
+                writeln!(w, "{}", rule.join("  "))?;
+            }
+            Format::Markdown => {
+                let rule: Vec<_> = widths
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &width)| {
+                        let dashes = "-".repeat(width - 1);
+                        if i == 0 {
+                            format!("{}-", dashes)
+                        } else {
+                            format!("{}:", dashes)
+                        }
+                    })
+                    .collect();
+                writeln!(w, "| {} |", rule.join(" | "))?;
+            }
+        }
+        for row in &self.rows {
+            line(w, row)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A title above a table, followed by a blank line.
+pub fn write_heading(w: &mut impl io::Write, format: Format, title: &str) -> io::Result<()> {
+    match format {
+        Format::Table => writeln!(w, "{}\n", title),
+        Format::Markdown => writeln!(w, "## {}\n", title),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Table {
+        let mut table = Table::new(["Member", "Score"]);
+        table.push(["Ada", "120"]);
+        table.push(["Grace Hopper", "7"]);
+
+        table
+    }
+
+    #[test]
+    fn aligns_columns_for_the_terminal() -> anyhow::Result<()> {
+        let mut out = Vec::new();
+        table().write(&mut out, Format::Table)?;
+
+        assert_eq!(
+            String::from_utf8(out)?,
+            "Member        Score\n\
+             ------------  -----\n\
+             Ada             120\n\
+             Grace Hopper      7\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn writes_markdown() -> anyhow::Result<()> {
+        let mut out = Vec::new();
+        table().write(&mut out, Format::Markdown)?;
+
+        assert_eq!(
+            String::from_utf8(out)?,
+            "| Member       | Score |\n\
+             | ------------ | ----: |\n\
+             | Ada          |   120 |\n\
+             | Grace Hopper |     7 |\n"
+        );
+
+        Ok(())
+    }
+}
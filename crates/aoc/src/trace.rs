@@ -0,0 +1,100 @@
+use anyhow::{anyhow, Context};
+use std::{fs::File, io, path::Path, str::FromStr, sync::Mutex};
+use tracing::Level;
+use tracing_subscriber::{filter::Targets, fmt, layer::SubscriberExt, util::SubscriberInitExt};
+
+/// Enables a day's solver events, optionally at a given level: `4` or `8=debug`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceSpec {
+    pub day: u8,
+    pub level: Level,
+}
+
+impl TraceSpec {
+    /// Each year's crate is named `aoc-<year>` and has a module per day.
+    fn target(&self, year: u16) -> String {
+        format!("aoc_{}::day_{:02}", year, self.day)
+    }
+}
+
+impl FromStr for TraceSpec {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (day, level) = match s.split_once('=') {
+            Some((day, level)) => (
+                day,
+                level
+                    .parse()
+                    .map_err(|_| anyhow!("level is invalid: '{}'", level))?,
+            ),
+            None => (s, Level::TRACE),
+        };
+        let day = day
+            .parse()
+            .with_context(|| format!("day is invalid: '{}'", day))?;
+
+        Ok(Self { day, level })
+    }
+}
+
+/// Installs the global subscriber for a year's days, writing to stderr, or to
+/// `json_file` as JSON lines when given. Does nothing when no days are being
+/// traced.
+pub fn init(year: u16, specs: &[TraceSpec], json_file: Option<&Path>) -> anyhow::Result<()> {
+    if specs.is_empty() {
+        return Ok(());
+    }
+
+    let targets = specs.iter().fold(Targets::new(), |targets, spec| {
+        targets.with_target(spec.target(year), spec.level)
+    });
+    let registry = tracing_subscriber::registry().with(targets);
+
+    match json_file {
+        Some(path) => {
+            let file = File::create(path)
+                .with_context(|| format!("failed to create {}", path.display()))?;
+            registry
+                .with(fmt::layer().json().with_writer(Mutex::new(file)))
+                .try_init()?;
+        }
+        None => registry
+            .with(fmt::layer().with_writer(io::stderr))
+            .try_init()?,
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("4", 4, Level::TRACE)]
+    #[test_case("8=debug", 8, Level::DEBUG)]
+    #[test_case("6=INFO", 6, Level::INFO)]
+    fn parse_spec(s: &str, day: u8, level: Level) {
+        let spec: TraceSpec = s.parse().expect("must be a valid spec");
+
+        assert_eq!(spec, TraceSpec { day, level });
+    }
+
+    #[test_case("=debug")]
+    #[test_case("four")]
+    #[test_case("4=loud")]
+    fn parse_invalid_spec(s: &str) {
+        assert!(s.parse::<TraceSpec>().is_err());
+    }
+
+    #[test]
+    fn target_matches_module_path() {
+        let spec = TraceSpec {
+            day: 4,
+            level: Level::TRACE,
+        };
+
+        assert_eq!(spec.target(2021), "aoc_2021::day_04");
+    }
+}
@@ -0,0 +1,413 @@
+use crate::config::Config;
+use aoc_core::{
+    runner::{self, Outcome},
+    solutions::{Day, InputKind, Part, Year},
+    visualise,
+};
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    queue,
+    style::{Attribute, Color, Print, ResetColor, SetAttribute, SetForegroundColor},
+    terminal::{self, ClearType},
+};
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    io::{self, IsTerminal, Write},
+    panic,
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::Duration,
+};
+
+const LIST_WIDTH: u16 = 44;
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+const PREVIEW_LINES: usize = 40;
+const VISUALISATION_FPS: u32 = 20;
+
+enum Status {
+    NotRun,
+    Running,
+    Done(Outcome),
+}
+
+struct Entry {
+    day: &'static Day,
+    part: &'static Part,
+    status: Status,
+}
+
+impl Entry {
+    fn summary(&self) -> String {
+        let status = match &self.status {
+            Status::NotRun => "-".to_string(),
+            Status::Running => "running...".to_string(),
+            Status::Done(Outcome {
+                answer: Ok(answer),
+                elapsed,
+            }) => format!("{} ({:.1?})", answer, elapsed),
+            Status::Done(Outcome {
+                answer: Err(_),
+                elapsed,
+            }) => format!("error ({:.1?})", elapsed),
+        };
+
+        format!(
+            "Day {:02} Part {}  {}",
+            self.day.number, self.part.number, status
+        )
+    }
+
+    fn colour(&self) -> Color {
+        match &self.status {
+            Status::NotRun => Color::Grey,
+            Status::Running => Color::Yellow,
+            Status::Done(Outcome { answer: Ok(_), .. }) => Color::Green,
+            Status::Done(Outcome { answer: Err(_), .. }) => Color::Red,
+        }
+    }
+}
+
+/// Each day's puzzle input, read once so that solvers running on other
+/// threads can borrow it for as long as the dashboard is open.
+type PuzzleInputs = BTreeMap<u8, &'static str>;
+
+struct App {
+    year: &'static Year,
+    puzzle_inputs: PuzzleInputs,
+    entries: Vec<Entry>,
+    selected: usize,
+    input_kind: InputKind,
+    scroll: usize,
+    detail: Vec<String>,
+    message: String,
+    sender: Sender<(usize, InputKind, Outcome)>,
+    receiver: Receiver<(usize, InputKind, Outcome)>,
+}
+
+impl App {
+    fn new(year: &'static Year, puzzle_inputs: PuzzleInputs, input_kind: InputKind) -> Self {
+        let entries = year
+            .days
+            .iter()
+            .flat_map(|day| {
+                day.parts.iter().map(move |part| Entry {
+                    day,
+                    part,
+                    status: Status::NotRun,
+                })
+            })
+            .collect();
+        let (sender, receiver) = mpsc::channel();
+
+        let mut app = Self {
+            year,
+            puzzle_inputs,
+            entries,
+            selected: 0,
+            input_kind,
+            scroll: 0,
+            detail: vec![],
+            message: String::new(),
+            sender,
+            receiver,
+        };
+        app.refresh_detail();
+
+        app
+    }
+
+    fn input(&self, day: &Day) -> &'static str {
+        match self.input_kind {
+            InputKind::Example => day.example_input,
+            InputKind::Puzzle => self.puzzle_inputs[&day.number],
+        }
+    }
+
+    fn selected(&self) -> &Entry {
+        &self.entries[self.selected]
+    }
+
+    fn select(&mut self, idx: usize) {
+        if idx < self.entries.len() && idx != self.selected {
+            self.selected = idx;
+            self.scroll = 0;
+            self.refresh_detail();
+        }
+    }
+
+    fn run(&mut self, idx: usize) {
+        let input = self.input(self.entries[idx].day);
+        let entry = &mut self.entries[idx];
+        if matches!(entry.status, Status::Running) {
+            return;
+        }
+        entry.status = Status::Running;
+
+        let part = entry.part;
+        let kind = self.input_kind;
+        let sender = self.sender.clone();
+        thread::spawn(move || {
+            let outcome = runner::run(part, input);
+            // The dashboard may have been closed while the solver was running.
+            let _ = sender.send((idx, kind, outcome));
+        });
+    }
+
+    fn run_all(&mut self) {
+        for idx in 0..self.entries.len() {
+            self.run(idx);
+        }
+    }
+
+    /// Collects finished runs, returning whether anything changed.
+    fn receive(&mut self) -> bool {
+        let mut changed = false;
+        while let Ok((idx, kind, outcome)) = self.receiver.try_recv() {
+            // Results for the other input are stale once the input is toggled.
+            self.entries[idx].status = if kind == self.input_kind {
+                Status::Done(outcome)
+            } else {
+                Status::NotRun
+            };
+            changed = true;
+        }
+
+        if changed {
+            self.refresh_detail();
+        }
+
+        changed
+    }
+
+    fn toggle_input(&mut self) {
+        self.input_kind = self.input_kind.toggle();
+        for entry in &mut self.entries {
+            if let Status::Done(_) = entry.status {
+                entry.status = Status::NotRun;
+            }
+        }
+        self.scroll = 0;
+        self.refresh_detail();
+    }
+
+    /// Plays the selected part's visualisation until it ends or a key is pressed.
+    fn visualise(&mut self, out: &mut impl Write) -> anyhow::Result<()> {
+        let entry = self.selected();
+        let Some(visualise) = entry.part.visualise else {
+            self.message = format!(
+                "no visualisation available for day {} part {}",
+                entry.day.number, entry.part.number
+            );
+            return Ok(());
+        };
+
+        match visualise(self.input(entry.day)) {
+            Ok(frames) => visualise::play(out, &frames, VISUALISATION_FPS, || {
+                Ok(event::poll(Duration::ZERO)? && matches!(event::read()?, Event::Key(_)))
+            })?,
+            Err(e) => self.message = format!("visualisation failed: {:#}", e),
+        }
+
+        queue!(out, cursor::Hide)?;
+
+        Ok(())
+    }
+
+    fn refresh_detail(&mut self) {
+        let entry = self.selected();
+        let input = self.input(entry.day);
+        let mut detail = vec![];
+
+        detail.push(format!("Output ({} input)", self.input_kind));
+        match &entry.status {
+            Status::NotRun => detail.push("  not run yet, press 'r' to run".to_string()),
+            Status::Running => detail.push("  running...".to_string()),
+            Status::Done(outcome) => match &outcome.answer {
+                Ok(answer) => detail.push(format!("  {} in {:.2?}", answer, outcome.elapsed)),
+                Err(e) => detail.push(format!("  error: {:#}", e)),
+            },
+        }
+
+        detail.push(String::new());
+        detail.push(format!("Input ({} lines)", input.lines().count()));
+        preview(&mut detail, input);
+
+        detail.push(String::new());
+        detail.push("Parsed".to_string());
+        match (entry.day.parse)(input) {
+            Ok(parsed) => preview(&mut detail, &parsed),
+            Err(e) => detail.push(format!("  error: {:#}", e)),
+        }
+
+        self.detail = detail;
+    }
+
+    /// Returns `false` once the user has asked to quit.
+    fn handle_key(
+        &mut self,
+        out: &mut impl Write,
+        key: KeyEvent,
+        page: usize,
+    ) -> anyhow::Result<bool> {
+        self.message.clear();
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(false),
+            KeyCode::Up | KeyCode::Char('k') => self.select(self.selected.saturating_sub(1)),
+            KeyCode::Down | KeyCode::Char('j') => self.select(self.selected + 1),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(page),
+            KeyCode::PageDown => {
+                self.scroll = (self.scroll + page).min(self.detail.len().saturating_sub(1));
+            }
+            KeyCode::Enter | KeyCode::Char('r') => self.run(self.selected),
+            KeyCode::Char('a') => self.run_all(),
+            KeyCode::Char('i') => self.toggle_input(),
+            KeyCode::Char('v') => self.visualise(out)?,
+            _ => {}
+        }
+
+        Ok(true)
+    }
+
+    fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        let body_height = height.saturating_sub(2);
+        let detail_width = usize::from(width.saturating_sub(LIST_WIDTH + 2));
+
+        queue!(out, terminal::Clear(ClearType::All), cursor::MoveTo(0, 0))?;
+        queue!(
+            out,
+            SetAttribute(Attribute::Reverse),
+            Print(fit(
+                &format!(
+                    " AoC {} | {} input | j/k select  r run  a run all  i toggle input  v visualise  PgUp/PgDn scroll  q quit",
+                    self.year.number, self.input_kind
+                ),
+                usize::from(width)
+            )),
+            SetAttribute(Attribute::Reset),
+        )?;
+
+        for (row, entry) in self.entries.iter().enumerate().take(body_height.into()) {
+            let marker = if row == self.selected { '>' } else { ' ' };
+            queue!(
+                out,
+                cursor::MoveTo(0, row as u16 + 1),
+                SetForegroundColor(entry.colour()),
+                Print(fit(
+                    &format!("{} {}", marker, entry.summary()),
+                    LIST_WIDTH.into()
+                )),
+                ResetColor,
+            )?;
+        }
+
+        for (row, line) in self
+            .detail
+            .iter()
+            .skip(self.scroll)
+            .take(body_height.into())
+            .enumerate()
+        {
+            queue!(
+                out,
+                cursor::MoveTo(LIST_WIDTH + 2, row as u16 + 1),
+                Print(fit(line, detail_width)),
+            )?;
+        }
+
+        queue!(
+            out,
+            cursor::MoveTo(0, height.saturating_sub(1)),
+            Print(fit(&self.message, usize::from(width))),
+        )?;
+
+        out.flush()
+    }
+}
+
+fn preview(detail: &mut Vec<String>, text: &str) {
+    detail.extend(text.lines().take(PREVIEW_LINES).map(|l| format!("  {}", l)));
+
+    let remaining = text.lines().count().saturating_sub(PREVIEW_LINES);
+    if remaining > 0 {
+        detail.push(format!("  ... {} more lines", remaining));
+    }
+}
+
+fn fit(s: &str, width: usize) -> String {
+    s.chars().take(width).collect()
+}
+
+/// Opens the interactive dashboard, falling back to running every solver
+/// with plain output when stdout is not a terminal.
+pub fn run(year: &'static Year, config: &Config, input_kind: InputKind) -> anyhow::Result<()> {
+    let mut stdout = io::stdout();
+    let puzzle_inputs = year
+        .days
+        .iter()
+        .map(|day| {
+            let input = match config.input(day, InputKind::Puzzle)? {
+                Cow::Borrowed(input) => input,
+                Cow::Owned(input) => input.leak(),
+            };
+            Ok((day.number, input))
+        })
+        .collect::<anyhow::Result<PuzzleInputs>>()?;
+
+    if !stdout.is_terminal() {
+        let app = App::new(year, puzzle_inputs, input_kind);
+        for entry in &app.entries {
+            let outcome = runner::run(entry.part, app.input(entry.day));
+            runner::print_outcome(&mut stdout, entry.day, entry.part, &outcome)?;
+        }
+        return Ok(());
+    }
+
+    // Solver panics are reported in the dashboard, so the default hook
+    // would only scribble over the screen.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    terminal::enable_raw_mode()?;
+    queue!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    let result = event_loop(&mut stdout, App::new(year, puzzle_inputs, input_kind));
+
+    queue!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
+    stdout.flush()?;
+    terminal::disable_raw_mode()?;
+    panic::set_hook(hook);
+
+    result
+}
+
+fn event_loop(out: &mut impl Write, mut app: App) -> anyhow::Result<()> {
+    let mut dirty = true;
+
+    loop {
+        dirty |= app.receive();
+        if dirty {
+            app.draw(out)?;
+            dirty = false;
+        }
+
+        if !event::poll(POLL_INTERVAL)? {
+            continue;
+        }
+
+        match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => {
+                let page = usize::from(terminal::size()?.1.saturating_sub(2));
+                if !app.handle_key(out, key, page)? {
+                    return Ok(());
+                }
+                dirty = true;
+            }
+            Event::Resize(..) => dirty = true,
+            _ => {}
+        }
+    }
+}
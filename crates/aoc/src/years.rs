@@ -0,0 +1,39 @@
+use anyhow::anyhow;
+use aoc_core::solutions::Year;
+
+/// Every year with solutions, oldest first.
+pub const YEARS: &[&Year] = &[&aoc_2021::YEAR];
+
+/// Finds a year's solutions, defaulting to the latest.
+pub fn find_year(number: Option<u16>) -> anyhow::Result<&'static Year> {
+    match number {
+        Some(number) => YEARS
+            .iter()
+            .copied()
+            .find(|y| y.number == number)
+            .ok_or_else(|| anyhow!("{} has no solutions", number)),
+        None => YEARS
+            .last()
+            .copied()
+            .ok_or_else(|| anyhow!("there are no solutions")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn years_are_in_order() {
+        assert!(YEARS.windows(2).all(|w| w[0].number < w[1].number));
+    }
+
+    #[test]
+    fn defaults_to_the_latest_year() -> anyhow::Result<()> {
+        assert_eq!(find_year(None)?.number, YEARS[YEARS.len() - 1].number);
+        assert_eq!(find_year(Some(2021))?.number, 2021);
+        assert!(find_year(Some(2014)).is_err());
+
+        Ok(())
+    }
+}
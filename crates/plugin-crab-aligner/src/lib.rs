@@ -0,0 +1,102 @@
+//! An example plugin: a faster crab aligner for 2021 day 7, which can be run
+//! side by side with the built-in solver via `aoc plugins --day 7`.
+
+use anyhow::{anyhow, bail};
+use aoc_core::{answer::Answer, plugin::Entry};
+
+aoc_core::export_plugin![
+    Entry {
+        year: 2021,
+        day: 7,
+        part: 1,
+        name: c"crab-aligner",
+        solve: align_linearly,
+    },
+    Entry {
+        year: 2021,
+        day: 7,
+        part: 2,
+        name: c"crab-aligner",
+        solve: align_triangularly,
+    },
+];
+
+/// Parses the positions straight from bytes, without splitting into strings.
+fn parse_positions(input: &str) -> anyhow::Result<Vec<i64>> {
+    let mut positions = Vec::with_capacity(input.len() / 2);
+
+    for field in input.trim().split(',') {
+        if field.is_empty() {
+            bail!("position is empty");
+        }
+        let mut position: i64 = 0;
+        for b in field.bytes() {
+            if !b.is_ascii_digit() {
+                bail!("position is invalid: '{}'", field);
+            }
+            position = position
+                .checked_mul(10)
+                .and_then(|p| p.checked_add(i64::from(b - b'0')))
+                .ok_or_else(|| anyhow!("position is too large: '{}'", field))?;
+        }
+        positions.push(position);
+    }
+
+    Ok(positions)
+}
+
+/// Moving one step costs one fuel, so the median is optimal.
+fn align_linearly(input: &str) -> anyhow::Result<Answer> {
+    let mut positions = parse_positions(input)?;
+    let middle = positions.len() / 2;
+    let (_, &mut median, _) = positions.select_nth_unstable(middle);
+
+    Ok(positions
+        .iter()
+        .map(|p| p.abs_diff(median))
+        .sum::<u64>()
+        .into())
+}
+
+/// Each step costs one more than the last, and the optimum lies within half a
+/// step of the mean, so only the whole positions either side need checking.
+fn align_triangularly(input: &str) -> anyhow::Result<Answer> {
+    let positions = parse_positions(input)?;
+    let num_crabs = i64::try_from(positions.len())?;
+    let mean = positions.iter().sum::<i64>().div_euclid(num_crabs);
+
+    let fuel = |target: i64| -> u64 {
+        positions
+            .iter()
+            .map(|p| {
+                let distance = p.abs_diff(target);
+                distance * (distance + 1) / 2
+            })
+            .sum()
+    };
+
+    Ok(fuel(mean).min(fuel(mean + 1)).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_2021::day_07::{EXAMPLE_INPUT, PUZZLE_INPUT};
+
+    #[test]
+    fn agrees_with_the_built_in_solvers() -> anyhow::Result<()> {
+        assert_eq!(align_linearly(EXAMPLE_INPUT)?, 37);
+        assert_eq!(align_linearly(PUZZLE_INPUT)?, 355_764);
+        assert_eq!(align_triangularly(EXAMPLE_INPUT)?, 168);
+        assert_eq!(align_triangularly(PUZZLE_INPUT)?, 99_634_572);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_bad_positions() {
+        assert!(align_linearly("").is_err());
+        assert!(align_linearly("1,x").is_err());
+        assert!(align_triangularly("1,,2").is_err());
+    }
+}
@@ -1,7 +0,0 @@
-#[cfg(test)]
-mod part_01;
-#[cfg(test)]
-mod part_02;
-
-const EXAMPLE_INPUT: &str = include_str!("../resources/day_06/example_input.txt");
-const PUZZLE_INPUT: &str = include_str!("../resources/day_06/puzzle_input.txt");
@@ -1,14 +0,0 @@
-#[cfg(test)]
-mod day_01;
-#[cfg(test)]
-mod day_02;
-#[cfg(test)]
-mod day_03;
-#[cfg(test)]
-mod day_04;
-#[cfg(test)]
-mod day_06;
-#[cfg(test)]
-mod day_07;
-#[cfg(test)]
-mod day_08;